@@ -0,0 +1,306 @@
+//! `tyr` configuration file
+use error::Result;
+use filter::Filter;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use toml;
+
+/// `tyr` configuration, read from a TOML file.
+///
+/// ```toml
+/// [filter]
+/// include = ["EMP*", "DEPT"]
+/// exclude = ["BIN$*", "DR$*", "re:_(AUD|STG)$"]
+/// exclude_columns = ["*.ROW_VERSION", "EMP.SSN"]
+///
+/// [stats]
+/// max_age = 30
+///
+/// [json]
+/// "ORDERS.PAYLOAD" = "::orders::Payload"
+///
+/// [uuid]
+/// all = false
+/// columns = ["*_GUID"]
+///
+/// [text]
+/// trim_char = true
+///
+/// [rowid]
+/// fetch = true
+///
+/// [naming]
+/// strip_table_prefixes = ["TBL_"]
+/// strip_column_prefixes = ["C_"]
+/// singularize = true
+///
+/// [naming.tables]
+/// TBL_CUSTOMER_ORDERS = "Order"
+///
+/// [naming.columns]
+/// "TBL_CUSTOMER_ORDERS.C_CUST_NO" = "customer_id"
+///
+/// [batch]
+/// size = 1000
+///
+/// [fetch]
+/// array_size = 100
+///
+/// [[types]]
+/// column = "ORDERS.TOTAL"
+/// rust_type = "::billing::Money"
+/// from = "::billing::Money::from_decimal"
+/// to = "::billing::Money::to_decimal"
+///
+/// [[types]]
+/// pattern = "*_EMAIL"
+/// rust_type = "::contact::Email"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct Config {
+    /// Table and column filters.
+    #[get = "pub"]
+    #[serde(default)]
+    filter: FilterConfig,
+    /// `tyr stats` settings.
+    #[get = "pub"]
+    #[serde(default)]
+    stats: StatsConfig,
+    /// `Deserialize` types for JSON columns, keyed by `TABLE.COLUMN`.  Unlisted JSON columns map to
+    /// `serde_json::Value`.
+    #[get = "pub"]
+    #[serde(default)]
+    json: BTreeMap<String, String>,
+    /// `RAW(16)` to `uuid::Uuid` mapping settings.
+    #[get = "pub"]
+    #[serde(default)]
+    uuid: UuidConfig,
+    /// Character column settings.
+    #[get = "pub"]
+    #[serde(default)]
+    text: TextConfig,
+    /// `ROWID` settings.
+    #[get = "pub"]
+    #[serde(default)]
+    rowid: RowidConfig,
+    /// Naming rules for the generated structs and fields.
+    #[get = "pub"]
+    #[serde(default)]
+    naming: NamingConfig,
+    /// Generated array DML settings.
+    #[get = "pub"]
+    #[serde(default)]
+    batch: BatchConfig,
+    /// Generated query settings.
+    #[get = "pub"]
+    #[serde(default)]
+    fetch: FetchConfig,
+    /// Rust type overrides, taking precedence over the built in type mapping.
+    #[get = "pub"]
+    #[serde(default)]
+    types: Vec<TypeConfig>,
+}
+
+/// The `[filter]` section of the configuration.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct FilterConfig {
+    /// Table name include patterns.
+    #[get = "pub"]
+    #[serde(default)]
+    include: Vec<String>,
+    /// Table name exclude patterns.
+    #[get = "pub"]
+    #[serde(default)]
+    exclude: Vec<String>,
+    /// `TABLE.COLUMN` exclude patterns.
+    #[get = "pub"]
+    #[serde(default)]
+    exclude_columns: Vec<String>,
+}
+
+impl FilterConfig {
+    /// Add the configured patterns to the given filter.
+    pub fn apply(&self, filter: &mut Filter) -> Result<()> {
+        for pattern in &self.include {
+            filter.include(pattern)?;
+        }
+        for pattern in &self.exclude {
+            filter.exclude(pattern)?;
+        }
+        for pattern in &self.exclude_columns {
+            filter.exclude_column(pattern)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `[stats]` section of the configuration.
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct StatsConfig {
+    /// Statistics older than this many days are reported as stale.
+    #[get = "pub"]
+    #[serde(default = "default_max_age")]
+    max_age: u64,
+}
+
+impl Default for StatsConfig {
+    fn default() -> StatsConfig {
+        StatsConfig {
+            max_age: default_max_age(),
+        }
+    }
+}
+
+/// The `[uuid]` section of the configuration.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct UuidConfig {
+    /// Map every `RAW(16)` column to `uuid::Uuid`.
+    #[get = "pub"]
+    #[serde(default)]
+    all: bool,
+    /// Column name patterns selecting the `RAW(16)` columns to map to `uuid::Uuid`.
+    #[get = "pub"]
+    #[serde(default)]
+    columns: Vec<String>,
+}
+
+/// The `[naming]` section of the configuration.  The rules only affect Rust names; the generated SQL
+/// always uses the original Oracle names.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct NamingConfig {
+    /// Prefixes stripped from table names, i.e. `TBL_`.  Only the first matching prefix is stripped.
+    #[get = "pub"]
+    #[serde(default)]
+    strip_table_prefixes: Vec<String>,
+    /// Suffixes stripped from table names.  Only the first matching suffix is stripped.
+    #[get = "pub"]
+    #[serde(default)]
+    strip_table_suffixes: Vec<String>,
+    /// Prefixes stripped from column names, i.e. `C_`.  Only the first matching prefix is stripped.
+    #[get = "pub"]
+    #[serde(default)]
+    strip_column_prefixes: Vec<String>,
+    /// Suffixes stripped from column names.  Only the first matching suffix is stripped.
+    #[get = "pub"]
+    #[serde(default)]
+    strip_column_suffixes: Vec<String>,
+    /// Singularize table names, i.e. `CustomerOrder` for `CUSTOMER_ORDERS`.
+    #[get = "pub"]
+    #[serde(default)]
+    singularize: bool,
+    /// Struct names, keyed by table name, overriding the rules above.
+    #[get = "pub"]
+    #[serde(default)]
+    tables: BTreeMap<String, String>,
+    /// Field names, keyed by `TABLE.COLUMN`, overriding the rules above.
+    #[get = "pub"]
+    #[serde(default)]
+    columns: BTreeMap<String, String>,
+}
+
+/// The `[rowid]` section of the configuration.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct RowidConfig {
+    /// Fetch the `ROWID` pseudo-column with every row, and use it to locate rows for writes.
+    #[get = "pub"]
+    #[serde(default)]
+    fetch: bool,
+}
+
+/// The `[text]` section of the configuration.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct TextConfig {
+    /// Trim the trailing space padding from fetched `CHAR` and `NCHAR` values.
+    #[get = "pub"]
+    #[serde(default)]
+    trim_char: bool,
+}
+
+/// A `[[types]]` entry of the configuration, overriding the Rust type of the matching columns.
+///
+/// Exactly one of `oracle_type`, `column` or `pattern` selects the columns.  Values are converted
+/// from and to the built in mapping's Rust type with `from` and `to`, or with `From` if omitted.
+#[derive(Clone, Debug, Default, Deserialize, Getters)]
+pub struct TypeConfig {
+    /// The Oracle data type, i.e. `NUMBER`.
+    #[get = "pub"]
+    #[serde(default)]
+    oracle_type: Option<String>,
+    /// The `TABLE.COLUMN` name.
+    #[get = "pub"]
+    #[serde(default)]
+    column: Option<String>,
+    /// A column name glob (or 're:' prefixed regex) pattern.
+    #[get = "pub"]
+    #[serde(default)]
+    pattern: Option<String>,
+    /// The Rust type, i.e. `::billing::Money`.
+    #[get = "pub"]
+    rust_type: String,
+    /// Path of a `fn(Base) -> T` converting fetched values.
+    #[get = "pub"]
+    #[serde(default)]
+    from: Option<String>,
+    /// Path of a `fn(&T) -> Base` converting bound values.
+    #[get = "pub"]
+    #[serde(default)]
+    to: Option<String>,
+}
+
+/// The `[batch]` section of the configuration.
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct BatchConfig {
+    /// The number of rows the generated array DML sends per round trip.
+    #[get = "pub"]
+    #[serde(default = "default_batch_size")]
+    size: usize,
+}
+
+impl Default for BatchConfig {
+    fn default() -> BatchConfig {
+        BatchConfig {
+            size: default_batch_size(),
+        }
+    }
+}
+
+/// The default array DML batch size, in rows.
+fn default_batch_size() -> usize {
+    1000
+}
+
+/// The `[fetch]` section of the configuration.
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct FetchConfig {
+    /// The number of rows the generated queries fetch per round trip.
+    #[get = "pub"]
+    #[serde(default = "default_fetch_array_size")]
+    array_size: u32,
+}
+
+impl Default for FetchConfig {
+    fn default() -> FetchConfig {
+        FetchConfig {
+            array_size: default_fetch_array_size(),
+        }
+    }
+}
+
+/// The default fetch array size, in rows, matching ODPI-C.
+fn default_fetch_array_size() -> u32 {
+    100
+}
+
+/// The default statistics max age, in days.
+fn default_max_age() -> u64 {
+    30
+}
+
+/// Read the configuration file at the given path.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Config> {
+    let mut contents = String::new();
+    File::open(path)?.read_to_string(&mut contents)?;
+    Ok(toml::from_str(&contents)?)
+}
@@ -1,13 +1,12 @@
 //! `tyr` context
-use mimir;
+use config::Config;
+use filter::{Filter, Pattern};
+use schema::Version;
+use types::Override;
 
 /// `tyr` context
 #[derive(Builder, Getters)]
 pub struct Context {
-    /// `mimir` context
-    #[get = "pub"]
-    #[builder(default = "self.default_db_context()?")]
-    db_context: mimir::Context,
     /// Connection string.
     #[get = "pub"]
     conn_string: String,
@@ -17,14 +16,52 @@ pub struct Context {
     /// Password used for db connection.
     #[get = "pub"]
     password: String,
-}
-
-impl ContextBuilder {
-    /// Generate the default db context.
-    fn default_db_context(&self) -> Result<mimir::Context, String> {
-        match mimir::Context::create() {
-            Ok(ctxt) => Ok(ctxt),
-            Err(e) => Err(e.description().to_string()),
-        }
-    }
+    /// The configuration file settings.
+    #[get = "pub"]
+    #[builder(default)]
+    config: Config,
+    /// Table and column filters.
+    #[get = "pub"]
+    #[builder(default)]
+    filter: Filter,
+    /// The user the generated code will run as, if not the connected user.
+    #[get = "pub"]
+    #[builder(default)]
+    target_user: Option<String>,
+    /// The oldest Oracle version the generated code must run against.
+    #[get = "pub"]
+    #[builder(default)]
+    target_version: Option<Version>,
+    /// Map every `RAW(16)` column to `uuid::Uuid`.
+    #[get = "pub"]
+    #[builder(default)]
+    raw16_uuid: bool,
+    /// Column name patterns selecting the `RAW(16)` columns to map to `uuid::Uuid`.
+    #[get = "pub"]
+    #[builder(default)]
+    uuid_columns: Vec<Pattern>,
+    /// Trim the trailing space padding from fetched `CHAR` and `NCHAR` values.
+    #[get = "pub"]
+    #[builder(default)]
+    trim_char: bool,
+    /// Fetch the `ROWID` pseudo-column with every row, and use it to locate rows for writes.
+    #[get = "pub"]
+    #[builder(default)]
+    fetch_rowid: bool,
+    /// Configured Rust type overrides.
+    #[get = "pub"]
+    #[builder(default)]
+    overrides: Vec<Override>,
+    /// Fail generation on columns without a Rust type mapping, rather than skipping them.
+    #[get = "pub"]
+    #[builder(default)]
+    strict: bool,
+    /// The number of rows the generated array DML sends per round trip.
+    #[get = "pub"]
+    #[builder(default = "1000")]
+    batch_size: usize,
+    /// The number of rows the generated queries fetch per round trip.
+    #[get = "pub"]
+    #[builder(default = "100")]
+    fetch_array_size: u32,
 }
@@ -11,22 +11,53 @@ error_chain! {
     foreign_links {
         Io(::std::io::Error);
         FromUtf8(::std::string::FromUtf8Error);
+        Glob(::glob::PatternError);
         Mimir(::mimir::Error);
         Mustache(::mustache::Error);
+        Regex(::regex::Error);
         Term(::term::Error);
+        Toml(::toml::de::Error);
     }
     errors {
+        BatchSize(rows: String) {
+            description("The batch size must be a whole number of rows!")
+            display("The batch size '{}' must be a whole number of rows!", rows)
+        }
+        ColumnPattern(pattern: String) {
+            description("Column exclusions must be of the form TABLE.COLUMN!")
+            display("Column exclusion '{}' must be of the form TABLE.COLUMN!", pattern)
+        }
         ConnectionString {
             description("The connection string is a required command line argument!")
             display("The connection string is a required command line argument!")
         }
+        FetchArraySize(rows: String) {
+            description("The fetch array size must be a whole number of rows!")
+            display("The fetch array size '{}' must be a whole number of rows!", rows)
+        }
         Max {
             description("")
             display("")
         }
+        MaxAge(days: String) {
+            description("The statistics max age must be a whole number of days!")
+            display("The statistics max age '{}' must be a whole number of days!", days)
+        }
         Stdout {
             description("Unable to open the stdout terminal for writing!")
             display("Unable to open the stdout terminal for writing!")
         }
+        TypeOverride(rust_type: String) {
+            description("Type overrides must give exactly one of oracle_type, column or pattern!")
+            display("Type override to '{}' must give exactly one of oracle_type, column or pattern!", rust_type)
+        }
+        Unmapped(count: usize) {
+            description("Columns have no Rust type mapping!")
+            display("{} column(s) have no Rust type mapping!", count)
+        }
+        Version(version: String) {
+            description("Versions must be of the form MAJOR[.MINOR]!")
+            display("Version '{}' must be of the form MAJOR[.MINOR]!", version)
+        }
     }
 }
@@ -0,0 +1,241 @@
+//! `tyr` table and column filters
+use error::{ErrorKind, Result};
+use glob;
+use regex::Regex;
+
+/// Prefix used to mark a pattern as a regular expression rather than a glob.
+const REGEX_PREFIX: &str = "re:";
+
+/// A name matching pattern, either a glob (the default) or a regular expression (prefixed with
+/// `re:`).
+#[derive(Clone, Debug)]
+pub enum Pattern {
+    /// A glob pattern, i.e. `BIN$*`.
+    Glob(glob::Pattern),
+    /// A regular expression, i.e. `re:^(DR|BIN)\$`.
+    Regex(Regex),
+}
+
+impl Pattern {
+    /// Parse a pattern string.
+    pub fn new(pattern: &str) -> Result<Pattern> {
+        if pattern.starts_with(REGEX_PREFIX) {
+            Ok(Pattern::Regex(Regex::new(&pattern[REGEX_PREFIX.len()..])?))
+        } else {
+            Ok(Pattern::Glob(glob::Pattern::new(pattern)?))
+        }
+    }
+
+    /// Does the given name match this pattern?
+    pub fn matches(&self, name: &str) -> bool {
+        match *self {
+            Pattern::Glob(ref glob) => glob.matches(name),
+            Pattern::Regex(ref regex) => regex.is_match(name),
+        }
+    }
+
+    /// The equivalent Oracle `LIKE` pattern (escaped with `\`), if there is one.  Only globs made of
+    /// literal characters, `*` and `?` translate exactly; regular expressions never do.
+    pub fn like(&self) -> Option<String> {
+        let glob = match *self {
+            Pattern::Glob(ref glob) => glob.as_str(),
+            Pattern::Regex(_) => return None,
+        };
+        let mut like = String::new();
+
+        for c in glob.chars() {
+            match c {
+                '*' => like.push('%'),
+                '?' => like.push('_'),
+                '[' | ']' => return None,
+                '%' | '_' | '\\' => {
+                    like.push('\\');
+                    like.push(c);
+                }
+                '\'' => like.push_str("''"),
+                c => like.push(c),
+            }
+        }
+
+        Some(like)
+    }
+}
+
+/// A `<column> [NOT] LIKE` condition for the given (translated) pattern.
+fn like(column: &str, not: bool, like: &str) -> String {
+    format!("{} {}LIKE '{}' ESCAPE '\\'", column, if not { "NOT " } else { "" }, like)
+}
+
+/// Table and column filters.  Tables are kept if they match any include pattern (or there are no
+/// include patterns) and match no exclude pattern.  Columns are dropped if they match a column
+/// exclusion for their table.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    /// Table name include patterns.
+    include: Vec<Pattern>,
+    /// Table name exclude patterns.
+    exclude: Vec<Pattern>,
+    /// Column exclusions as (table pattern, column pattern) pairs.
+    exclude_columns: Vec<(Pattern, Pattern)>,
+}
+
+impl Filter {
+    /// Add a table include pattern.
+    pub fn include(&mut self, pattern: &str) -> Result<&mut Filter> {
+        self.include.push(Pattern::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Add a table exclude pattern.
+    pub fn exclude(&mut self, pattern: &str) -> Result<&mut Filter> {
+        self.exclude.push(Pattern::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Add a column exclusion of the form `TABLE.COLUMN`, where both halves are patterns.  The
+    /// table pattern is split at the first `.`, so it should not contain one itself.
+    pub fn exclude_column(&mut self, pattern: &str) -> Result<&mut Filter> {
+        let mut parts = pattern.splitn(2, '.');
+        let table = parts.next().unwrap_or("");
+        let column = parts.next().ok_or_else(|| ErrorKind::ColumnPattern(pattern.to_string()))?;
+        self.exclude_columns.push((Pattern::new(table)?, Pattern::new(column)?));
+        Ok(self)
+    }
+
+    /// Should the given table be queried and rendered?
+    pub fn table(&self, table: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches(table)))
+            && !self.exclude.iter().any(|p| p.matches(table))
+    }
+
+    /// Should the given column of the given table be kept?
+    pub fn column(&self, table: &str, column: &str) -> bool {
+        !self.exclude_columns
+            .iter()
+            .any(|&(ref t, ref c)| t.matches(table) && c.matches(column))
+    }
+
+    /// SQL conditions on the given table name column narrowing a query to the tables this filter
+    /// keeps, as far as the patterns translate to `LIKE`.  The conditions may keep more tables than
+    /// the filter, so `table` still has the final say.
+    pub fn table_conditions(&self, column: &str) -> Vec<String> {
+        let mut conditions = Vec::new();
+        let includes: Option<Vec<String>> = self.include.iter().map(Pattern::like).collect();
+
+        if let Some(includes) = includes {
+            if !includes.is_empty() {
+                let includes: Vec<String> = includes.iter().map(|p| like(column, false, p)).collect();
+                conditions.push(format!("({})", includes.join(" OR ")));
+            }
+        }
+        for pattern in self.exclude.iter().filter_map(Pattern::like) {
+            conditions.push(like(column, true, &pattern));
+        }
+
+        conditions
+    }
+
+    /// SQL conditions on the given column name column dropping the columns of the given table this
+    /// filter excludes, as far as the patterns translate to `LIKE`.
+    pub fn column_conditions(&self, table: &str, column: &str) -> Vec<String> {
+        self.exclude_columns
+            .iter()
+            .filter(|&&(ref t, _)| t.matches(table))
+            .filter_map(|&(_, ref c)| c.like())
+            .map(|pattern| like(column, true, &pattern))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Filter, Pattern};
+
+    #[test]
+    fn glob_pattern() {
+        let pattern = Pattern::new("BIN$*").expect("valid glob");
+        assert!(pattern.matches("BIN$ABC"));
+        assert!(!pattern.matches("XBIN$ABC"));
+    }
+
+    #[test]
+    fn regex_pattern() {
+        let pattern = Pattern::new(r"re:^(DR|BIN)\$").expect("valid regex");
+        assert!(pattern.matches("DR$IDX"));
+        assert!(pattern.matches("BIN$ABC"));
+        assert!(!pattern.matches("XBIN$ABC"));
+        // Without the prefix, the same string is a glob, matching literally.
+        assert!(!Pattern::new(r"^(DR|BIN)\$").expect("valid glob").matches("DR$IDX"));
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        assert!(Pattern::new("re:(").is_err());
+        assert!(Pattern::new("[").is_err());
+    }
+
+    #[test]
+    fn no_includes_keeps_everything() {
+        let filter = Filter::default();
+        assert!(filter.table("ANYTHING"));
+        assert!(filter.column("ANYTHING", "ANY_COLUMN"));
+    }
+
+    #[test]
+    fn exclude_beats_include() {
+        let mut filter = Filter::default();
+        let _ = filter.include("APP_*").expect("valid include");
+        let _ = filter.exclude("re:_TMP$").expect("valid exclude");
+        assert!(filter.table("APP_USERS"));
+        assert!(!filter.table("APP_USERS_TMP"));
+        assert!(!filter.table("OTHER"));
+    }
+
+    #[test]
+    fn exclude_columns() {
+        let mut filter = Filter::default();
+        let _ = filter.exclude_column("APP_*.AUDIT_*").expect("valid exclusion");
+        assert!(!filter.column("APP_USERS", "AUDIT_TS"));
+        assert!(filter.column("APP_USERS", "NAME"));
+        assert!(filter.column("OTHER", "AUDIT_TS"));
+        assert!(filter.exclude_column("NO_COLUMN").is_err());
+    }
+
+    #[test]
+    fn like_translation() {
+        let like = |pattern: &str| Pattern::new(pattern).expect("valid pattern").like();
+        assert_eq!(like("BIN$*"), Some("BIN$%".to_string()));
+        assert_eq!(like("APP_?"), Some("APP\\__".to_string()));
+        assert_eq!(like("50%'S"), Some("50\\%''S".to_string()));
+        assert_eq!(like("[AB]*"), None);
+        assert_eq!(like("re:^APP_"), None);
+    }
+
+    #[test]
+    fn pushed_down_conditions() {
+        let mut filter = Filter::default();
+        assert!(filter.table_conditions("table_name").is_empty());
+        let _ = filter.include("APP_*").expect("valid include");
+        let _ = filter.include("CORE").expect("valid include");
+        let _ = filter.exclude("re:_TMP$").expect("valid exclude");
+        let _ = filter.exclude("*$*").expect("valid exclude");
+        assert_eq!(
+            filter.table_conditions("table_name"),
+            vec![
+                "(table_name LIKE 'APP\\_%' ESCAPE '\\' OR table_name LIKE 'CORE' ESCAPE '\\')",
+                "table_name NOT LIKE '%$%' ESCAPE '\\'",
+            ]
+        );
+        // One untranslatable include means no include condition at all.
+        let _ = filter.include("re:^X").expect("valid include");
+        assert_eq!(filter.table_conditions("table_name"), vec!["table_name NOT LIKE '%$%' ESCAPE '\\'"]);
+
+        let _ = filter.exclude_column("APP_*.AUDIT_*").expect("valid exclusion");
+        let _ = filter.exclude_column("APP_*.re:_TS$").expect("valid exclusion");
+        assert_eq!(
+            filter.column_conditions("APP_USERS", "column_name"),
+            vec!["column_name NOT LIKE 'AUDIT\\_%' ESCAPE '\\'"]
+        );
+        assert!(filter.column_conditions("OTHER", "column_name").is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+//! `tyr` Rust identifiers from Oracle names
+use config::NamingConfig;
+use inflector::cases::pascalcase::to_pascal_case;
+use inflector::cases::snakecase::to_snake_case;
+use inflector::string::singularize::to_singular;
+use std::collections::BTreeSet;
+use tmpl;
+
+/// Rust keywords, strict and reserved, which are suffixed with `_` when used as identifiers.  Raw
+/// identifiers aren't used, as the getters, setters and helpers derived from field names would not
+/// be valid identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn", "else",
+    "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+    "move", "mut", "override", "priv", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+    "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Methods and private fields of the generated structs, which fields must not collide with.
+const METHODS: &[&str] = &[
+    "define", "delete", "dirty", "fetch", "fetch_after", "fetch_iter", "fetch_page", "fetch_rows", "field_var",
+    "from_row", "insert", "insert_many", "insert_returning", "is_dirty", "locate", "located", "merge", "query",
+    "select", "update", "update_many", "upsert", "upsert_on", "validate",
+];
+
+/// Types the generated code uses from the prelude or defines itself, which struct names must not
+/// shadow.  The types it imports are read from the template.
+const TYPES: &[&str] = &[
+    "AnyColumn", "Batch", "BatchAborted", "BatchError", "BatchMode", "Bound", "Box", "Column", "Condition", "Default",
+    "Dirty", "Eq", "Err", "Fn", "FnOnce", "Iterator", "LobStream", "Located", "None", "Ok", "Option", "PartialEq",
+    "Query", "Queryable", "Rows", "Sized", "Some", "SortOrder", "String", "Vec", "YearMonthInterval",
+];
+
+lazy_static! {
+    /// The types and modules the generated code imports, which struct and column module names must
+    /// not shadow.
+    static ref IMPORTS: Vec<String> = imports(tmpl::ORM_TMPL);
+}
+
+/// The names the given code imports with single line `use` declarations, i.e. `Hash` and `Hasher`
+/// for `use std::hash::{Hash, Hasher};`, or `StdError` for `use std::error::Error as StdError;`.
+fn imports(code: &str) -> Vec<String> {
+    code.lines()
+        .filter(|line| line.starts_with("use "))
+        .flat_map(|line| {
+            let names = line.trim_right_matches(';').rsplit("::").next().unwrap_or("");
+            names
+                .trim_matches(|c| c == '{' || c == '}')
+                .split(',')
+                .map(|name| name.trim().rsplit(" as ").next().unwrap_or("").to_string())
+                .collect::<Vec<String>>()
+        })
+        .filter(|name| name != "self")
+        .collect()
+}
+
+/// The given reserved names along with the imported ones.
+fn reserved<'a>(names: &[&'a str]) -> Vec<&'a str> {
+    names.iter().cloned().chain(IMPORTS.iter().map(|name| &name[..])).collect()
+}
+
+/// Replace the characters Oracle allows in identifiers (i.e. `$` and `#` or anything in a quoted
+/// identifier) but Rust doesn't with `_`.
+fn sanitize(name: &str) -> String {
+    name.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
+}
+
+/// Make the name a usable identifier, prefixing it if empty or it would start with a digit, and
+/// suffixing it if reserved.
+fn escape(name: String, prefix: &str, reserved: &[&str]) -> String {
+    let name = if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}{}", prefix, name)
+    } else {
+        name
+    };
+
+    if KEYWORDS.contains(&&name[..]) || reserved.contains(&&name[..]) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// Strip the first of the given prefixes and then the first of the given suffixes that match,
+/// unless nothing would be left.
+fn strip<'a>(name: &'a str, prefixes: &[String], suffixes: &[String]) -> &'a str {
+    let name = prefixes
+        .iter()
+        .find(|prefix| name.len() > prefix.len() && name.starts_with(&prefix[..]))
+        .map_or(name, |prefix| &name[prefix.len()..]);
+    suffixes
+        .iter()
+        .find(|suffix| name.len() > suffix.len() && name.ends_with(&suffix[..]))
+        .map_or(name, |suffix| &name[..name.len() - suffix.len()])
+}
+
+/// The struct field name for the given Oracle column of the given table, i.e. `type_` for `TYPE`,
+/// following the naming rules.
+pub fn field_name(naming: &NamingConfig, table_name: &str, column_name: &str) -> String {
+    let name = match naming.columns().get(&format!("{}.{}", table_name, column_name)) {
+        Some(rename) => sanitize(rename),
+        None => {
+            let stripped = strip(column_name, naming.strip_column_prefixes(), naming.strip_column_suffixes());
+            to_snake_case(&sanitize(stripped))
+        }
+    };
+    escape(name, "c_", METHODS)
+}
+
+/// The struct name for the given Oracle table name, i.e. `OrderLine` for `ORDER_LINE`, following the
+/// naming rules.
+pub fn struct_name(naming: &NamingConfig, table_name: &str) -> String {
+    let name = match naming.tables().get(table_name) {
+        Some(rename) => sanitize(rename),
+        None => {
+            let stripped = strip(table_name, naming.strip_table_prefixes(), naming.strip_table_suffixes());
+            let snake = to_snake_case(&sanitize(stripped));
+            to_pascal_case(&if *naming.singularize() { to_singular(&snake) } else { snake })
+        }
+    };
+    escape(name, "T", &reserved(TYPES))
+}
+
+/// The name of the module holding the typed columns of the given struct, i.e. `order_line` for
+/// `OrderLine`.
+pub fn module_name(struct_name: &str) -> String {
+    escape(to_snake_case(struct_name), "t_", &reserved(&[]))
+}
+
+/// The identifiers the generated code derives from a field name: the field and its getter, its
+/// setters, its bind helper and its LOB stream accessor.
+fn derived(name: &str) -> Vec<String> {
+    vec![
+        name.to_string(),
+        format!("set_{}", name),
+        format!("{}_mut", name),
+        format!("bind_{}", name),
+        format!("{}_stream", name),
+    ]
+}
+
+/// Make the given names unique, in order, by numbering the later duplicates.  The first name to
+/// claim an identifier keeps it, so the result only depends on the order of the names.  Names are
+/// kept within `max_len` bytes, truncating before the number if need be.
+pub fn unique(names: Vec<String>, max_len: usize) -> Vec<String> {
+    unique_with(names, max_len, |name| vec![name.to_string()])
+}
+
+/// Make the given field names unique, as `unique` does, so that none of the identifiers derived from
+/// them collide either, i.e. field `x`'s `set_x` with a field named `set_x`.
+pub fn unique_fields(names: Vec<String>) -> Vec<String> {
+    unique_with(names, usize::MAX, derived)
+}
+
+/// Make the given names unique, in order, so that none of the identifiers `derive` gives for them
+/// collide.
+fn unique_with<F>(names: Vec<String>, max_len: usize, derive: F) -> Vec<String>
+where
+    F: Fn(&str) -> Vec<String>,
+{
+    let mut taken = BTreeSet::new();
+    let mut result = Vec::new();
+
+    for name in names {
+        let mut candidate = name.clone();
+        let mut n = 1;
+        while derive(&candidate).iter().any(|d| taken.contains(d)) {
+            n += 1;
+            let suffix = format!("_{}", n);
+            let mut base = name.clone();
+            while base.len() + suffix.len() > max_len {
+                base.pop();
+            }
+            candidate = format!("{}{}", base, suffix);
+        }
+        taken.extend(derive(&candidate));
+        result.push(candidate);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        escape, field_name, imports, module_name, sanitize, strip, struct_name, unique, unique_fields, METHODS, TYPES,
+    };
+    use config::NamingConfig;
+    use tmpl::ORM_TMPL;
+
+    #[test]
+    fn sanitize_oracle_characters() {
+        assert_eq!(sanitize("A$B#C"), "A_B_C");
+        assert_eq!(sanitize("with space"), "with_space");
+    }
+
+    #[test]
+    fn escape_keywords_and_reserved() {
+        assert_eq!(escape("type".to_string(), "c_", METHODS), "type_");
+        assert_eq!(escape("self".to_string(), "c_", METHODS), "self_");
+        assert_eq!(escape("insert".to_string(), "c_", METHODS), "insert_");
+        assert_eq!(escape("Option".to_string(), "T", TYPES), "Option_");
+        assert_eq!(escape("name".to_string(), "c_", METHODS), "name");
+    }
+
+    #[test]
+    fn escape_empty_and_leading_digits() {
+        assert_eq!(escape("".to_string(), "c_", METHODS), "c_");
+        assert_eq!(escape("1st".to_string(), "c_", METHODS), "c_1st");
+        assert_eq!(escape("2".to_string(), "T", TYPES), "T2");
+    }
+
+    #[test]
+    fn strip_first_match_only() {
+        let prefixes = vec!["TBL_".to_string(), "T_".to_string()];
+        let suffixes = vec!["_V".to_string()];
+        assert_eq!(strip("TBL_ORDERS_V", &prefixes, &suffixes), "ORDERS");
+        assert_eq!(strip("T_TBL_ORDERS", &prefixes, &suffixes), "TBL_ORDERS");
+        assert_eq!(strip("TBL_", &prefixes, &suffixes), "TBL_");
+        assert_eq!(strip("_V", &prefixes, &suffixes), "_V");
+    }
+
+    #[test]
+    fn names_follow_the_rules() {
+        let naming = NamingConfig::default();
+        assert_eq!(field_name(&naming, "ORDERS", "TYPE"), "type_");
+        assert_eq!(field_name(&naming, "ORDERS", "ORDER_ID"), "order_id");
+        assert_eq!(struct_name(&naming, "ORDER_LINE"), "OrderLine");
+        assert_eq!(struct_name(&naming, "OPTION"), "Option_");
+    }
+
+    #[test]
+    fn unique_numbers_later_duplicates() {
+        let names = vec!["a".to_string(), "b".to_string(), "a".to_string(), "a".to_string()];
+        assert_eq!(unique(names, usize::MAX), vec!["a", "b", "a_2", "a_3"]);
+    }
+
+    #[test]
+    fn unique_truncates_at_max_len() {
+        let names = vec!["abcdefghij".to_string(), "abcdefghij".to_string(), "abcdefghij".to_string()];
+        assert_eq!(unique(names, 10), vec!["abcdefghij", "abcdefgh_2", "abcdefgh_3"]);
+    }
+
+    #[test]
+    fn unique_skips_numbers_already_taken() {
+        let names = vec!["abcdefgh_2".to_string(), "abcdefghij".to_string(), "abcdefghij".to_string()];
+        assert_eq!(unique(names, 10), vec!["abcdefgh_2", "abcdefghij", "abcdefgh_3"]);
+    }
+
+    #[test]
+    fn unique_fields_avoid_derived_names() {
+        let names = vec!["set_x".to_string(), "x".to_string()];
+        assert_eq!(unique_fields(names), vec!["set_x", "x_2"]);
+        let names = vec!["x".to_string(), "set_x".to_string(), "x_mut".to_string(), "bind_x".to_string()];
+        assert_eq!(unique_fields(names), vec!["x", "set_x_2", "x_mut_2", "bind_x_2"]);
+    }
+
+    #[test]
+    fn imports_from_use_declarations() {
+        let code = "use a::B;\nuse c::{self, D, e};\n    use f::G;\nuse h::I as J;\nfn k() {}";
+        assert_eq!(imports(code), vec!["B", "D", "e", "J"]);
+    }
+
+    #[test]
+    fn template_types_are_reserved() {
+        // The types the template declares before the per-table code.
+        let support = &ORM_TMPL[..ORM_TMPL.find("{{#tables}}").expect("per-table section")];
+        for line in support.lines() {
+            let line = line.trim_left_matches("pub ");
+            for kind in &["struct ", "enum ", "trait "] {
+                if line.starts_with(kind) {
+                    let name: String = line[kind.len()..].chars().take_while(|c| c.is_alphanumeric()).collect();
+                    assert!(TYPES.contains(&&name[..]), "{} is not reserved", name);
+                }
+            }
+        }
+
+        let imported = imports(ORM_TMPL);
+        for name in &["BTreeSet", "FromStr", "Hasher", "NaiveDate", "ODPITimestamp", "Read", "StdError", "fmt", "str"] {
+            assert!(imported.iter().any(|i| i == name), "{} is not imported", name);
+        }
+        let naming = NamingConfig::default();
+        assert_eq!(struct_name(&naming, "HASHER"), "Hasher_");
+        assert_eq!(struct_name(&naming, "NAIVE_DATE"), "NaiveDate_");
+        assert_eq!(module_name("Fmt"), "fmt_");
+    }
+}
@@ -17,21 +17,33 @@ extern crate error_chain;
 #[macro_use]
 extern crate getset;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 mod macros;
 #[macro_use]
 extern crate serde_derive;
 
 extern crate clap;
+extern crate glob;
 extern crate inflector;
 extern crate mimir;
 extern crate mustache;
+extern crate regex;
 extern crate rustc_serialize;
 extern crate term;
+extern crate toml;
 
+mod config;
 mod context;
 mod error;
+mod filter;
+mod ident;
+mod privs;
 mod run;
+mod schema;
+mod stats;
 mod tmpl;
+mod types;
 mod util;
 
 use std::io::{self, Write};
@@ -0,0 +1,261 @@
+//! `tyr` table privileges
+use error::{Error, ErrorKind, Result};
+use mimir::enums::ODPINativeTypeNum::Bytes;
+use mimir::enums::ODPIOracleTypeNum::Varchar;
+use mimir::{flags, Connection};
+use std::collections::{BTreeMap, BTreeSet};
+use term;
+
+/// Object privileges granted on the user's tables, including those on roles visible to the session.
+const TAB_PRIVS: &str = r"SELECT GRANTEE, TABLE_NAME, PRIVILEGE
+FROM user_tab_privs
+WHERE owner = USER
+UNION
+SELECT ROLE, TABLE_NAME, PRIVILEGE
+FROM role_tab_privs
+WHERE owner = USER AND column_name IS NULL";
+
+/// Roles granted (directly or through other roles) to the grantee.  This requires access to
+/// `dba_role_privs`.
+const ROLE_PRIVS: &str = r"SELECT GRANTED_ROLE
+FROM dba_role_privs
+START WITH grantee=:grantee
+CONNECT BY PRIOR granted_role = grantee";
+
+/// The operations the target user may perform on a table.
+#[derive(Clone, Debug, Getters)]
+pub struct Privileges {
+    /// Can the user select from the table?
+    #[get = "pub"]
+    select: bool,
+    /// Can the user insert into the table?
+    #[get = "pub"]
+    insert: bool,
+    /// Can the user update the table?
+    #[get = "pub"]
+    update: bool,
+    /// Can the user delete from the table?
+    #[get = "pub"]
+    delete: bool,
+    /// Where each granted privilege came from, i.e. `SELECT via role APP_RO`.
+    #[get = "pub"]
+    sources: Vec<String>,
+}
+
+impl Default for Privileges {
+    /// The owner of a table can perform every operation.
+    fn default() -> Privileges {
+        Privileges {
+            select: true,
+            insert: true,
+            update: true,
+            delete: true,
+            sources: vec!["owner".to_string()],
+        }
+    }
+}
+
+impl Privileges {
+    /// No privileges at all.
+    fn none() -> Privileges {
+        Privileges {
+            select: false,
+            insert: false,
+            update: false,
+            delete: false,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Only the given privileges.
+    #[cfg(test)]
+    pub fn granted(select: bool, insert: bool, update: bool, delete: bool) -> Privileges {
+        Privileges {
+            select,
+            insert,
+            update,
+            delete,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Is this table read-only for the target user?
+    pub fn read_only(&self) -> bool {
+        self.select && !(self.insert || self.update || self.delete)
+    }
+
+    /// The privileges that were not granted.
+    fn missing(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if !self.select {
+            missing.push("SELECT");
+        }
+        if !self.insert {
+            missing.push("INSERT");
+        }
+        if !self.update {
+            missing.push("UPDATE");
+        }
+        if !self.delete {
+            missing.push("DELETE");
+        }
+        missing
+    }
+}
+
+/// The privileges of the target user on each of the given tables, along with whether its role
+/// grants were visible.  Without a target user, the connected user owns every table and so has
+/// every privilege.  Only `dba_role_privs` shows the roles granted to another user, so without
+/// access to it only direct and `PUBLIC` grants are considered.
+pub fn load<'a, I>(conn: &Connection, target_user: Option<&str>, tables: I) -> Result<(BTreeMap<String, Privileges>, bool)>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let mut privileges = BTreeMap::new();
+    let grantee = match target_user {
+        Some(user) => user.to_uppercase(),
+        None => {
+            for table in tables {
+                privileges.insert(table.clone(), Default::default());
+            }
+            return Ok((privileges, true));
+        }
+    };
+
+    let (roles, roles_visible) = match roles(conn, &grantee) {
+        Ok(roles) => (roles, true),
+        Err(ref e) if inaccessible(e) => (BTreeSet::new(), false),
+        Err(e) => return Err(e),
+    };
+
+    for table in tables {
+        privileges.insert(table.clone(), Privileges::none());
+    }
+
+    let stmt = conn.prepare_stmt(Some(TAB_PRIVS), None, false)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (mut found, _) = stmt.fetch()?;
+
+    while found {
+        let (_, grantee_data) = stmt.get_query_value(1)?;
+        let (_, table_data) = stmt.get_query_value(2)?;
+        let (_, privilege_data) = stmt.get_query_value(3)?;
+        let granted_to = grantee_data.get_string();
+
+        let source = if granted_to == grantee {
+            Some("directly".to_string())
+        } else if granted_to == "PUBLIC" {
+            Some("via PUBLIC".to_string())
+        } else if roles.contains(&granted_to) {
+            Some(format!("via role {}", granted_to))
+        } else {
+            None
+        };
+
+        if let (Some(source), Some(privs)) = (source, privileges.get_mut(&table_data.get_string())) {
+            let privilege = privilege_data.get_string();
+            let granted = match &privilege[..] {
+                "SELECT" | "READ" => Some(&mut privs.select),
+                "INSERT" => Some(&mut privs.insert),
+                "UPDATE" => Some(&mut privs.update),
+                "DELETE" => Some(&mut privs.delete),
+                _ => None,
+            };
+            if let Some(granted) = granted {
+                *granted = true;
+                privs.sources.push(format!("{} {}", privilege, source));
+            }
+        }
+
+        let (f, _) = stmt.fetch()?;
+        found = f;
+    }
+
+    stmt.close(None)?;
+    Ok((privileges, roles_visible))
+}
+
+/// The connected user, who owns the described tables.
+pub fn current_user(conn: &Connection) -> Result<String> {
+    let stmt = conn.prepare_stmt(Some("SELECT USER FROM dual"), None, false)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (_, _) = stmt.fetch()?;
+    let (_, data) = stmt.get_query_value(1)?;
+    let current = data.get_string();
+    stmt.close(None)?;
+    Ok(current)
+}
+
+/// Is the error Oracle reporting a dictionary view that doesn't exist or can't be read?
+fn inaccessible(e: &Error) -> bool {
+    e.iter().any(|cause| {
+        let msg = cause.to_string();
+        msg.contains("ORA-00942") || msg.contains("ORA-01031")
+    })
+}
+
+/// The roles granted, directly or indirectly, to the given user.
+fn roles(conn: &Connection, grantee: &str) -> Result<BTreeSet<String>> {
+    let stmt = conn.prepare_stmt(Some(ROLE_PRIVS), None, false)?;
+    let grantee_var = conn.new_var(Varchar, Bytes, 1, 256, false, false)?;
+    grantee_var.set_from_bytes(0, grantee)?;
+    stmt.bind_by_name(":grantee", &grantee_var)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (mut found, _) = stmt.fetch()?;
+    let mut roles = BTreeSet::new();
+
+    while found {
+        let (_, data) = stmt.get_query_value(1)?;
+        roles.insert(data.get_string());
+        let (f, _) = stmt.fetch()?;
+        found = f;
+    }
+
+    stmt.close(None)?;
+    Ok(roles)
+}
+
+/// Report the tables that came out read-only (or inaccessible) for the target user, and why.
+pub fn report(privileges: &BTreeMap<String, Privileges>, roles_visible: bool) -> Result<()> {
+    let limited: Vec<_> = privileges.iter().filter(|&(_, p)| !p.missing().is_empty()).collect();
+
+    if limited.is_empty() {
+        return Ok(());
+    }
+
+    let mut t = term::stdout().ok_or_else(|| ErrorKind::Stdout)?;
+    t.attr(term::Attr::Bold)?;
+    t.fg(term::color::YELLOW)?;
+    writeln!(t, "{:#^80}", " Privileges ")?;
+    t.reset()?;
+
+    for (table, privs) in limited {
+        let granted = if privs.sources.is_empty() {
+            "nothing granted".to_string()
+        } else {
+            privs.sources.join(", ")
+        };
+        let kind = if privs.read_only() {
+            "read-only"
+        } else if privs.select {
+            "restricted"
+        } else {
+            "skipped"
+        };
+        t.fg(term::color::YELLOW)?;
+        write!(t, "{}: {}", table, kind)?;
+        t.reset()?;
+        writeln!(t, " ({}; missing {})", granted, privs.missing().join(", "))?;
+    }
+
+    if !roles_visible {
+        writeln!(
+            t,
+            "Role grants to the target user can't be read without access to dba_role_privs; only direct \
+             and PUBLIC grants were considered."
+        )?;
+    }
+    t.flush()?;
+
+    Ok(())
+}
@@ -7,25 +7,30 @@
 // modified, or distributed except according to those terms.
 
 //! `tyr` runtime
-use clap::{App, Arg};
+use clap::{App, Arg, SubCommand};
+use config;
 use context::{Context, ContextBuilder};
 use error::{ErrorKind, Result};
-use mimir::enums::ODPINativeTypeNum::Bytes;
-use mimir::enums::ODPIOracleTypeNum::Varchar;
-use mimir::{flags, Connection, Data, TypeInfo};
+use filter::{Filter, Pattern};
+use mimir::{self, flags, Connection, Data, TypeInfo};
+use privs;
+use schema::{self, Features, TableBuilder, Version};
+use stats;
 use std::collections::BTreeMap;
 use tmpl;
+use types::Override;
 use util;
 
 /// User space table names query.
 const TABLE_NAMES: &str = r"select table_name from user_tables";
-/// Describe user space tables Oracle SQL.
+/// Describe user space tables Oracle SQL, completed by the columns the server version describes.
 const DESC: &str = r"SELECT TABLE_NAME, COLUMN_NAME, DATA_TYPE, DATA_TYPE_MOD,
 DATA_TYPE_OWNER, DATA_LENGTH, DATA_PRECISION, DATA_SCALE, NULLABLE, COLUMN_ID, DEFAULT_LENGTH,
 NUM_DISTINCT, LOW_VALUE, HIGH_VALUE, DENSITY, NUM_NULLS, NUM_BUCKETS, LAST_ANALYZED, SAMPLE_SIZE,
 CHARACTER_SET_NAME, CHAR_COL_DECL_LENGTH, GLOBAL_STATS, USER_STATS, AVG_COL_LEN, CHAR_LENGTH,
-CHAR_USED, V80_FMT_IMAGE, DATA_UPGRADED, HISTOGRAM, DEFAULT_ON_NULL, IDENTITY_COLUMN,
-EVALUATION_EDITION, UNUSABLE_BEFORE, UNUSABLE_BEGINNING
+CHAR_USED, V80_FMT_IMAGE, DATA_UPGRADED, HISTOGRAM";
+/// The describe query `FROM` and `WHERE` clauses.
+const DESC_FROM: &str = r"
 FROM user_tab_columns
 WHERE table_name=:table_name";
 
@@ -50,8 +55,7 @@ pub struct QueryDataByCol {
 pub type Rows = BTreeMap<u32, Vec<QueryDataByCol>>;
 
 /// Connect to the database.
-fn conn(ctxt: &Context) -> Result<()> {
-    let db_ctxt = ctxt.db_context();
+fn connect(db_ctxt: &mimir::Context, ctxt: &Context) -> Result<Connection> {
     let mut common_create_params = db_ctxt.init_common_create_params()?;
     common_create_params.set_encoding("UTF-8")?;
     common_create_params.set_nchar_encoding("UTF-8")?;
@@ -65,7 +69,43 @@ fn conn(ctxt: &Context) -> Result<()> {
         None,
     )?;
 
-    let user_tables = conn.prepare_stmt(Some(TABLE_NAMES), None, false)?;
+    Ok(conn)
+}
+
+/// Detect the server version and the features it supports.
+fn server_features(conn: &Connection) -> Result<Features> {
+    let version_info = conn.get_server_version()?;
+    let version = Version::new(version_info.version_num() as u32, version_info.release_num() as u32);
+    Ok(Features::new(version))
+}
+
+/// The describe query, selecting the 12.1+ identity, default on null and edition columns only
+/// from servers that have them.
+fn desc_sql(features: &Features) -> String {
+    let mut desc = DESC.to_string();
+    if *features.default_on_null() {
+        desc.push_str(", DEFAULT_ON_NULL");
+    }
+    if *features.identity_columns() {
+        desc.push_str(", IDENTITY_COLUMN");
+    }
+    if *features.editions() {
+        desc.push_str(",\nEVALUATION_EDITION, UNUSABLE_BEFORE, UNUSABLE_BEGINNING");
+    }
+    desc.push_str(DESC_FROM);
+    desc
+}
+
+/// Describe the (filtered) user tables, using a describe query the server supports.  The filters
+/// are pushed into the queries where they translate to SQL, and checked again on the results.
+fn describe(ctxt: &Context, conn: &Connection, features: &Features) -> Result<BTreeMap<String, Rows>> {
+    let desc = desc_sql(features);
+    let mut table_names_sql = TABLE_NAMES.to_string();
+    let conditions = ctxt.filter().table_conditions("table_name");
+    if !conditions.is_empty() {
+        table_names_sql.push_str(&format!(" where {}", conditions.join(" and ")));
+    }
+    let user_tables = conn.prepare_stmt(Some(&table_names_sql), None, false)?;
 
     let _ = user_tables.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
     let (mut found, _) = user_tables.fetch()?;
@@ -73,26 +113,35 @@ fn conn(ctxt: &Context) -> Result<()> {
 
     while found {
         let (_id_type, data) = user_tables.get_query_value(1)?;
-        table_names.insert(data.get_string(), Default::default());
+        let table_name = data.get_string();
+        if ctxt.filter().table(&table_name) {
+            table_names.insert(table_name, Default::default());
+        }
         let (f, _) = user_tables.fetch()?;
         found = f;
     }
 
     for (table, rows) in &mut table_names {
-        let table_desc = conn.prepare_stmt(Some(DESC), None, false)?;
-        let table_name_var = conn.new_var(Varchar, Bytes, 1, 256, false, false)?;
-        table_name_var.set_from_bytes(0, table)?;
-        table_desc.bind_by_name(":table_name", &table_name_var)?;
+        let mut desc = desc.clone();
+        for condition in ctxt.filter().column_conditions(table, "COLUMN_NAME") {
+            desc.push_str(&format!("\nAND {}", condition));
+        }
+        let (table_desc, _table_name_var) = util::prepare_for_table(conn, &desc, table)?;
 
         let cols = table_desc.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
         let (mut found, mut buffer_row_index) = table_desc.fetch()?;
 
         while found {
             let mut row_data = Vec::new();
+            let mut keep = true;
             for i in 1..=cols {
                 let mut query_data_by_col: QueryDataByCol = Default::default();
                 let query_info = table_desc.get_query_info(i)?;
                 let (_, data) = table_desc.get_query_value(i)?;
+                if query_info.name() == "COLUMN_NAME" && !ctxt.filter().column(table, &data.get_string()) {
+                    keep = false;
+                    break;
+                }
                 query_data_by_col.set_column_name(query_info.name());
                 query_data_by_col.set_type_info(query_info.type_info());
                 if !data.null() {
@@ -101,7 +150,9 @@ fn conn(ctxt: &Context) -> Result<()> {
                 row_data.push(query_data_by_col);
             }
 
-            rows.insert(buffer_row_index, row_data);
+            if keep {
+                rows.insert(buffer_row_index, row_data);
+            }
             let (f, b) = table_desc.fetch()?;
             found = f;
             buffer_row_index = b;
@@ -112,12 +163,51 @@ fn conn(ctxt: &Context) -> Result<()> {
     }
 
     user_tables.close(None)?;
+    Ok(table_names)
+}
+
+/// Describe the user tables and generate the ORM.
+fn generate(db_ctxt: &mimir::Context, ctxt: &Context) -> Result<()> {
+    let conn = connect(db_ctxt, ctxt)?;
+    let server = server_features(&conn)?;
+    let table_names = describe(ctxt, &conn, &server)?;
+    let features = match *ctxt.target_version() {
+        Some(version) if version < *server.version() => Features::new(version),
+        _ => server,
+    };
+    let owner = privs::current_user(&conn)?;
+    let target_user = ctxt.target_user().as_ref().map(|user| user.to_uppercase()).filter(|user| *user != owner);
+    let grantee = target_user.as_ref().map(|user| &user[..]);
+    let (mut privileges, roles_visible) = privs::load(&conn, grantee, table_names.keys())?;
     util::pretty_print_tables(&table_names)?;
-    tmpl::render(&table_names)?;
+    privs::report(&privileges, roles_visible)?;
+
+    let mut tables = BTreeMap::new();
+    for (table_name, rows) in &table_names {
+        let primary_key = schema::primary_key(&conn, table_name)?;
+        let table = TableBuilder::default()
+            .name(table_name.clone())
+            .columns(schema::columns(rows, &schema::checks(&conn, table_name)?)?)
+            .unique_keys(schema::unique_keys(&conn, table_name, &primary_key)?)
+            .primary_key(primary_key)
+            .privileges(privileges.remove(table_name).unwrap_or_default())
+            .owner(target_user.as_ref().map(|_| owner.clone()))
+            .build()?;
+        tables.insert(table_name.clone(), table);
+    }
+    tmpl::render(&tables, &features, ctxt)?;
 
     Ok(())
 }
 
+/// Describe the user tables and report on their optimizer statistics.
+fn stats(db_ctxt: &mimir::Context, ctxt: &Context, max_age: u64) -> Result<()> {
+    let conn = connect(db_ctxt, ctxt)?;
+    let server = server_features(&conn)?;
+    let table_names = describe(ctxt, &conn, &server)?;
+    stats::report(&conn, &table_names, max_age)
+}
+
 /// CLI Runtime
 pub fn run() -> Result<i32> {
     use std::io::{self, Write};
@@ -135,24 +225,218 @@ pub fn run() -> Result<i32> {
         )
         .arg(Arg::with_name("username").short("u").long("username").takes_value(true).value_name("USERNAME"))
         .arg(Arg::with_name("password").short("p").long("password").takes_value(true).value_name("PASSWORD"))
+        .arg(
+            Arg::with_name("config")
+                .long("config")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Read table/column filters and other settings from the given TOML file."),
+        )
+        .arg(
+            Arg::with_name("include")
+                .short("i")
+                .long("include")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("PATTERN")
+                .help("Only generate tables matching the given glob (or 're:' prefixed regex) pattern."),
+        )
+        .arg(
+            Arg::with_name("exclude")
+                .short("e")
+                .long("exclude")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("PATTERN")
+                .help("Skip tables matching the given glob (or 're:' prefixed regex) pattern."),
+        )
+        .arg(
+            Arg::with_name("exclude_column")
+                .long("exclude-column")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("TABLE.COLUMN")
+                .help("Skip columns matching the given TABLE.COLUMN pattern pair."),
+        )
+        .arg(
+            Arg::with_name("target_user")
+                .long("target-user")
+                .takes_value(true)
+                .value_name("USER")
+                .help("Only generate the operations the given user has been granted on each table."),
+        )
+        .arg(
+            Arg::with_name("target_version")
+                .long("target-version")
+                .takes_value(true)
+                .value_name("VERSION")
+                .help("Avoid generating SQL the given Oracle version (i.e. 11.2) lacks, if older than the server."),
+        )
+        .arg(
+            Arg::with_name("uuid")
+                .long("uuid")
+                .help("Map every RAW(16) column to uuid::Uuid."),
+        )
+        .arg(
+            Arg::with_name("uuid_column")
+                .long("uuid-column")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("PATTERN")
+                .help("Map RAW(16) columns whose name matches the given glob (or 're:' prefixed regex) to uuid::Uuid."),
+        )
+        .arg(
+            Arg::with_name("trim_char")
+                .long("trim-char")
+                .help("Trim the trailing space padding from fetched CHAR and NCHAR values."),
+        )
+        .arg(
+            Arg::with_name("rowid")
+                .long("rowid")
+                .help("Fetch the ROWID with every row, and update and delete rows by ROWID."),
+        )
+        .arg(
+            Arg::with_name("strict")
+                .long("strict")
+                .help("Fail if any column has no Rust type mapping, rather than skipping it."),
+        )
+        .arg(
+            Arg::with_name("batch_size")
+                .long("batch-size")
+                .takes_value(true)
+                .value_name("ROWS")
+                .help("The number of rows the generated insert_many and update_many send per round trip."),
+        )
+        .arg(
+            Arg::with_name("fetch_array_size")
+                .long("fetch-array-size")
+                .takes_value(true)
+                .value_name("ROWS")
+                .help("The number of rows the generated queries fetch per round trip."),
+        )
+        .subcommand(
+            SubCommand::with_name("stats")
+                .about("Report per-table and per-column optimizer statistics.")
+                .arg(
+                    Arg::with_name("max_age")
+                        .long("max-age")
+                        .takes_value(true)
+                        .value_name("DAYS")
+                        .help("Flag statistics older than the given number of days as stale."),
+                ),
+        )
         .get_matches();
 
     let conn_string = matches.value_of("conn_string").ok_or(ErrorKind::ConnectionString)?;
     let username = matches.value_of("username").ok_or(ErrorKind::ConnectionString)?;
     let password = matches.value_of("password").ok_or(ErrorKind::ConnectionString)?;
+    let config = match matches.value_of("config") {
+        Some(path) => config::load(path)?,
+        None => Default::default(),
+    };
+
+    let mut filter: Filter = Default::default();
+    config.filter().apply(&mut filter)?;
+    for pattern in matches.values_of("include").into_iter().flat_map(|v| v) {
+        filter.include(pattern)?;
+    }
+    for pattern in matches.values_of("exclude").into_iter().flat_map(|v| v) {
+        filter.exclude(pattern)?;
+    }
+    for pattern in matches.values_of("exclude_column").into_iter().flat_map(|v| v) {
+        filter.exclude_column(pattern)?;
+    }
+
+    let mut uuid_columns = Vec::new();
+    for pattern in config.uuid().columns() {
+        uuid_columns.push(Pattern::new(pattern)?);
+    }
+    for pattern in matches.values_of("uuid_column").into_iter().flat_map(|v| v) {
+        uuid_columns.push(Pattern::new(pattern)?);
+    }
+
+    let mut overrides = Vec::new();
+    for type_config in config.types() {
+        overrides.push(Override::new(type_config)?);
+    }
+
+    let batch_size = match matches.value_of("batch_size") {
+        Some(rows) => rows.parse().map_err(|_| ErrorKind::BatchSize(rows.to_string()))?,
+        None => *config.batch().size(),
+    };
+    if batch_size == 0 {
+        return Err(ErrorKind::BatchSize(batch_size.to_string()).into());
+    }
+
+    let fetch_array_size = match matches.value_of("fetch_array_size") {
+        Some(rows) => rows.parse().map_err(|_| ErrorKind::FetchArraySize(rows.to_string()))?,
+        None => *config.fetch().array_size(),
+    };
+    if fetch_array_size == 0 {
+        return Err(ErrorKind::FetchArraySize(fetch_array_size.to_string()).into());
+    }
+
     let ctxt = ContextBuilder::default()
         .conn_string(conn_string.to_string())
         .username(username.to_string())
         .password(password.to_string())
+        .filter(filter)
+        .config(config.clone())
+        .target_user(matches.value_of("target_user").map(|user| user.to_string()))
+        .target_version(match matches.value_of("target_version") {
+            Some(version) => Some(Version::parse(version)?),
+            None => None,
+        })
+        .raw16_uuid(*config.uuid().all() || matches.is_present("uuid"))
+        .uuid_columns(uuid_columns)
+        .trim_char(*config.text().trim_char() || matches.is_present("trim_char"))
+        .fetch_rowid(*config.rowid().fetch() || matches.is_present("rowid"))
+        .overrides(overrides)
+        .strict(matches.is_present("strict"))
+        .batch_size(batch_size)
+        .fetch_array_size(fetch_array_size)
         .build()?;
+    let db_ctxt = mimir::Context::create()?;
 
-    match conn(&ctxt) {
+    let result = if let Some(stats_matches) = matches.subcommand_matches("stats") {
+        let max_age = match stats_matches.value_of("max_age") {
+            Some(days) => days.parse().map_err(|_| ErrorKind::MaxAge(days.to_string()))?,
+            None => *config.stats().max_age(),
+        };
+        stats(&db_ctxt, &ctxt, max_age)
+    } else {
+        generate(&db_ctxt, &ctxt)
+    };
+
+    match result {
         Ok(()) => {}
         Err(e) => {
-            writeln!(io::stderr(), "{}", ctxt.db_context().get_error())?;
+            writeln!(io::stderr(), "{}", db_ctxt.get_error())?;
             return Err(e);
         }
     }
 
     Ok(0)
 }
+
+#[cfg(test)]
+mod test {
+    use super::desc_sql;
+    use schema::{Features, Version};
+
+    #[test]
+    fn describe_what_the_server_has() {
+        let desc = desc_sql(&Features::new(Version::new(11, 2)));
+        assert!(!desc.contains("DEFAULT_ON_NULL"));
+        assert!(!desc.contains("IDENTITY_COLUMN"));
+        assert!(!desc.contains("EVALUATION_EDITION"));
+        assert!(desc.ends_with("HISTOGRAM\nFROM user_tab_columns\nWHERE table_name=:table_name"));
+
+        let desc = desc_sql(&Features::new(Version::new(12, 1)));
+        assert!(desc.contains("HISTOGRAM, DEFAULT_ON_NULL, IDENTITY_COLUMN,\nEVALUATION_EDITION"));
+    }
+}
@@ -0,0 +1,467 @@
+//! `tyr` schema model
+use error::{ErrorKind, Result};
+use mimir::{flags, Connection};
+use privs::Privileges;
+use regex::{self, Regex};
+use run::{QueryDataByCol, Rows};
+use std::fmt;
+use util;
+
+/// Primary key columns, in key order.
+const PRIMARY_KEY: &str = r"SELECT cols.COLUMN_NAME
+FROM user_constraints cons
+JOIN user_cons_columns cols ON cols.constraint_name = cons.constraint_name
+WHERE cons.constraint_type='P' AND cons.table_name=:table_name
+ORDER BY cols.position";
+
+/// Unique index columns, in key order.  This covers unique constraints, as they are enforced by
+/// unique indexes.
+const UNIQUE_KEYS: &str = r"SELECT idx.INDEX_NAME, cols.COLUMN_NAME
+FROM user_indexes idx
+JOIN user_ind_columns cols ON cols.index_name = idx.index_name
+WHERE idx.uniqueness='UNIQUE' AND idx.table_name=:table_name
+ORDER BY idx.index_name, cols.column_position";
+
+/// Check constraint conditions.  `SEARCH_CONDITION` is a `LONG`, so conditions are matched to
+/// columns after fetching.
+const CHECKS: &str = r"SELECT SEARCH_CONDITION
+FROM user_constraints
+WHERE constraint_type='C' AND table_name=:table_name";
+
+/// An Oracle server version, i.e. `12.1`.
+#[derive(Clone, Copy, Debug, Eq, Getters, Ord, PartialEq, PartialOrd)]
+pub struct Version {
+    /// The major version, i.e. `12`.
+    #[get = "pub"]
+    major: u32,
+    /// The release within the major version, i.e. `1`.
+    #[get = "pub"]
+    minor: u32,
+}
+
+impl Version {
+    /// Create a new version.
+    pub fn new(major: u32, minor: u32) -> Version {
+        Version { major, minor }
+    }
+
+    /// Parse a version of the form `MAJOR[.MINOR]`, i.e. `11.2` or `19`.
+    pub fn parse(version: &str) -> Result<Version> {
+        let mut parts = version.trim().splitn(3, '.');
+        let major = parts.next().and_then(|p| p.parse().ok());
+        let minor = parts.next().map_or(Some(0), |p| p.parse().ok());
+
+        match (major, minor) {
+            (Some(major), Some(minor)) => Ok(Version::new(major, minor)),
+            _ => Err(ErrorKind::Version(version.to_string()).into()),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The features available in a given Oracle version, used to pick compatible metadata queries and
+/// generated SQL.
+#[derive(Clone, Copy, Debug, Getters)]
+pub struct Features {
+    /// The version these features describe.
+    #[get = "pub"]
+    version: Version,
+    /// `GENERATED AS IDENTITY` columns (12.1+).
+    #[get = "pub"]
+    identity_columns: bool,
+    /// `DEFAULT ON NULL` column defaults (12.1+).
+    #[get = "pub"]
+    default_on_null: bool,
+    /// Editioned column metadata, i.e. `EVALUATION_EDITION` (12.1+).
+    #[get = "pub"]
+    editions: bool,
+    /// `OFFSET ... FETCH FIRST/NEXT` row limiting (12.1+).
+    #[get = "pub"]
+    fetch_first: bool,
+    /// The native `JSON` type (21.1+).
+    #[get = "pub"]
+    json_type: bool,
+    /// The native `BOOLEAN` type (23.1+).
+    #[get = "pub"]
+    boolean_type: bool,
+}
+
+impl Features {
+    /// The features available in the given version.
+    pub fn new(version: Version) -> Features {
+        let twelve_one = version >= Version::new(12, 1);
+        Features {
+            version,
+            identity_columns: twelve_one,
+            default_on_null: twelve_one,
+            editions: twelve_one,
+            fetch_first: twelve_one,
+            json_type: version >= Version::new(21, 1),
+            boolean_type: version >= Version::new(23, 1),
+        }
+    }
+}
+
+/// Table metadata used for generation.
+#[derive(Builder, Clone, Debug, Getters)]
+pub struct Table {
+    /// The table name.
+    #[get = "pub"]
+    name: String,
+    /// The table columns, in column order.
+    #[get = "pub"]
+    columns: Vec<Column>,
+    /// The primary key column names, in key order.  Empty if the table has no primary key.
+    #[get = "pub"]
+    #[builder(default)]
+    primary_key: Vec<String>,
+    /// The column names of each unique key other than the primary key, in key order.
+    #[get = "pub"]
+    #[builder(default)]
+    unique_keys: Vec<Vec<String>>,
+    /// The target user privileges on the table.
+    #[get = "pub"]
+    #[builder(default)]
+    privileges: Privileges,
+    /// The table owner, when generating for another target user, which must qualify the table name.
+    #[get = "pub"]
+    #[builder(default)]
+    owner: Option<String>,
+}
+
+/// Column metadata, as described by `user_tab_columns`.
+#[derive(Clone, Debug, Default, Getters, Setters)]
+pub struct Column {
+    /// The column name.
+    #[get = "pub"]
+    #[set]
+    column_name: String,
+    /// The Oracle data type, i.e. `NUMBER` or `VARCHAR2`.
+    #[get = "pub"]
+    #[set]
+    data_type: String,
+    /// The column length, in bytes.
+    #[get = "pub"]
+    #[set]
+    data_length: Option<u64>,
+    /// The column length, in characters, for character columns.
+    #[get = "pub"]
+    #[set]
+    char_length: Option<u64>,
+    /// Is the character column length measured in characters (`C`) or bytes (`B`)?
+    #[get = "pub"]
+    #[set]
+    char_used: Option<String>,
+    /// The decimal precision for `NUMBER` columns, or binary precision for `FLOAT` columns.
+    #[get = "pub"]
+    #[set]
+    data_precision: Option<u64>,
+    /// The digits to the right of the decimal point.  This can be negative.
+    #[get = "pub"]
+    #[set]
+    data_scale: Option<i64>,
+    /// Does the column allow nulls?
+    #[get = "pub"]
+    #[set]
+    nullable: bool,
+    /// Is this a `GENERATED AS IDENTITY` column?  Only described on 12.1+.
+    #[get = "pub"]
+    #[set]
+    identity: bool,
+    /// The column position in the table.
+    #[get = "pub"]
+    #[set]
+    column_id: Option<u64>,
+    /// The number of distinct values, if analyzed.
+    #[get = "pub"]
+    #[set]
+    num_distinct: Option<u64>,
+    /// The number of nulls, if analyzed.
+    #[get = "pub"]
+    #[set]
+    num_nulls: Option<u64>,
+    /// The column density, if analyzed.
+    #[get = "pub"]
+    #[set]
+    density: Option<f64>,
+    /// The histogram type, i.e. `NONE` or `FREQUENCY`.
+    #[get = "pub"]
+    #[set]
+    histogram: Option<String>,
+    /// When the column was last analyzed.
+    #[get = "pub"]
+    #[set]
+    last_analyzed: Option<String>,
+    /// The sample size used to analyze the column.
+    #[get = "pub"]
+    #[set]
+    sample_size: Option<u64>,
+    /// The check constraint conditions referencing the column.
+    #[get = "pub"]
+    #[set]
+    checks: Vec<String>,
+}
+
+impl Column {
+    /// The `ROWID` pseudo-column, which every heap and index-organized table has.
+    pub fn rowid() -> Column {
+        Column {
+            column_name: "ROWID".to_string(),
+            data_type: "ROWID".to_string(),
+            nullable: true,
+            ..Default::default()
+        }
+    }
+
+    /// A described column with the given name, data type, precision, scale and check constraint
+    /// conditions.
+    #[cfg(test)]
+    pub fn described(
+        name: &str,
+        data_type: &str,
+        precision: Option<u64>,
+        scale: Option<i64>,
+        checks: &[&str],
+    ) -> Column {
+        Column {
+            column_name: name.to_string(),
+            data_type: data_type.to_string(),
+            data_precision: precision,
+            data_scale: scale,
+            checks: checks.iter().map(|check| check.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// The column, made nullable.
+    #[cfg(test)]
+    pub fn optional(mut self) -> Column {
+        self.nullable = true;
+        self
+    }
+
+    /// The column, with the given length in bytes.
+    #[cfg(test)]
+    pub fn sized(mut self, data_length: u64) -> Column {
+        self.data_length = Some(data_length);
+        self
+    }
+
+    /// The character column, with the given length in characters and whether that is its length semantics.
+    #[cfg(test)]
+    pub fn chars(mut self, char_length: u64, char_semantics: bool) -> Column {
+        self.char_length = Some(char_length);
+        self.char_used = Some(if char_semantics { "C" } else { "B" }.to_string());
+        self
+    }
+
+    /// The column with the given nullability, and the given null count and analysis time statistics.
+    #[cfg(test)]
+    pub fn analyzed(mut self, nullable: bool, num_nulls: Option<u64>, last_analyzed: Option<&str>) -> Column {
+        self.nullable = nullable;
+        self.num_nulls = num_nulls;
+        self.last_analyzed = last_analyzed.map(|analyzed| analyzed.to_string());
+        self
+    }
+
+    /// Is the column length measured in characters rather than bytes?  This is always the case for
+    /// the national character set types.
+    pub fn char_semantics(&self) -> bool {
+        self.char_used.as_ref().map_or(false, |used| used == "C")
+    }
+
+    /// Build the column metadata from a row of the describe query.
+    pub fn from_row(row: &[QueryDataByCol]) -> Result<Column> {
+        let mut column: Column = Default::default();
+
+        for col in row {
+            let value = value(col)?;
+            match &(*col.column_name())[..] {
+                "COLUMN_NAME" => {
+                    column.set_column_name(value.unwrap_or_default());
+                }
+                "DATA_TYPE" => {
+                    column.set_data_type(value.unwrap_or_default());
+                }
+                "DATA_LENGTH" => {
+                    column.set_data_length(number(&value).map(|n| n as u64));
+                }
+                "CHAR_LENGTH" => {
+                    column.set_char_length(number(&value).map(|n| n as u64));
+                }
+                "CHAR_USED" => {
+                    column.set_char_used(value);
+                }
+                "DATA_PRECISION" => {
+                    column.set_data_precision(number(&value).map(|n| n as u64));
+                }
+                "DATA_SCALE" => {
+                    column.set_data_scale(number(&value).map(|n| n as i64));
+                }
+                "NULLABLE" => {
+                    column.set_nullable(value.map_or(false, |v| v == "Y"));
+                }
+                "IDENTITY_COLUMN" => {
+                    column.set_identity(value.map_or(false, |v| v == "YES"));
+                }
+                "COLUMN_ID" => {
+                    column.set_column_id(number(&value).map(|n| n as u64));
+                }
+                "NUM_DISTINCT" => {
+                    column.set_num_distinct(number(&value).map(|n| n as u64));
+                }
+                "NUM_NULLS" => {
+                    column.set_num_nulls(number(&value).map(|n| n as u64));
+                }
+                "DENSITY" => {
+                    column.set_density(number(&value));
+                }
+                "HISTOGRAM" => {
+                    column.set_histogram(value);
+                }
+                "LAST_ANALYZED" => {
+                    column.set_last_analyzed(value);
+                }
+                "SAMPLE_SIZE" => {
+                    column.set_sample_size(number(&value).map(|n| n as u64));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(column)
+    }
+}
+
+/// Build the column metadata for every described row of a table, attaching the given check
+/// constraint conditions to the columns they reference.
+pub fn columns(rows: &Rows, checks: &[String]) -> Result<Vec<Column>> {
+    let mut columns = Vec::new();
+
+    for row in rows.values() {
+        let mut column = Column::from_row(row)?;
+        if !checks.is_empty() {
+            let reference = column_reference(column.column_name())?;
+            let referenced = checks.iter().filter(|check| reference.is_match(check)).cloned().collect();
+            column.set_checks(referenced);
+        }
+        columns.push(column);
+    }
+
+    Ok(columns)
+}
+
+/// A regex matching the given column name as a whole (optionally quoted) identifier.
+fn column_reference(column_name: &str) -> Result<Regex> {
+    let pattern = format!(r#"(^|[^\w$#"])"?{}"?([^\w$#"]|$)"#, regex::escape(column_name));
+    Ok(Regex::new(&pattern)?)
+}
+
+/// Fetch the check constraint conditions of the given table.
+pub fn checks(conn: &Connection, table: &str) -> Result<Vec<String>> {
+    let (stmt, _table_name_var) = util::prepare_for_table(conn, CHECKS, table)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (mut found, _) = stmt.fetch()?;
+    let mut checks = Vec::new();
+
+    while found {
+        let (_, data) = stmt.get_query_value(1)?;
+        if !data.null() {
+            checks.push(data.get_string());
+        }
+        let (f, _) = stmt.fetch()?;
+        found = f;
+    }
+
+    stmt.close(None)?;
+    Ok(checks)
+}
+
+/// Fetch the primary key column names of the given table.
+pub fn primary_key(conn: &Connection, table: &str) -> Result<Vec<String>> {
+    let (stmt, _table_name_var) = util::prepare_for_table(conn, PRIMARY_KEY, table)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (mut found, _) = stmt.fetch()?;
+    let mut columns = Vec::new();
+
+    while found {
+        let (_, data) = stmt.get_query_value(1)?;
+        columns.push(data.get_string());
+        let (f, _) = stmt.fetch()?;
+        found = f;
+    }
+
+    stmt.close(None)?;
+    Ok(columns)
+}
+
+/// Fetch the column names of each unique key of the given table, other than the primary key.
+pub fn unique_keys(conn: &Connection, table: &str, primary_key: &[String]) -> Result<Vec<Vec<String>>> {
+    let (stmt, _table_name_var) = util::prepare_for_table(conn, UNIQUE_KEYS, table)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (mut found, _) = stmt.fetch()?;
+    let mut keys: Vec<Vec<String>> = Vec::new();
+    let mut last_index = None;
+
+    while found {
+        let (_, index_data) = stmt.get_query_value(1)?;
+        let (_, column_data) = stmt.get_query_value(2)?;
+        let index = index_data.get_string();
+        if last_index.as_ref() != Some(&index) {
+            keys.push(Vec::new());
+            last_index = Some(index);
+        }
+        if let Some(columns) = keys.last_mut() {
+            columns.push(column_data.get_string());
+        }
+        let (f, _) = stmt.fetch()?;
+        found = f;
+    }
+
+    stmt.close(None)?;
+    Ok(keys.into_iter().filter(|columns| columns[..] != *primary_key).collect())
+}
+
+/// Convert the column data to a string, if it isn't null.
+fn value(col: &QueryDataByCol) -> Result<Option<String>> {
+    match *col.data() {
+        Some(ref data) => Ok(Some(data.to_string(col.type_info())?)),
+        None => Ok(None),
+    }
+}
+
+/// Parse a numeric column value.
+fn number(value: &Option<String>) -> Option<f64> {
+    value.as_ref().and_then(|v| v.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod test {
+    use super::Version;
+
+    #[test]
+    fn parse_major_and_minor() {
+        assert_eq!(Version::parse("11.2").expect("valid version"), Version::new(11, 2));
+        assert_eq!(Version::parse(" 19 ").expect("valid version"), Version::new(19, 0));
+        assert_eq!(Version::parse("12.1.0.2.0").expect("valid version"), Version::new(12, 1));
+    }
+
+    #[test]
+    fn parse_malformed() {
+        for version in &["", ".", "12.", ".1", "x", "12.x", "-1", "1 2", "12..1"] {
+            assert!(Version::parse(version).is_err(), "'{}' parsed", version);
+        }
+    }
+
+    #[test]
+    fn order_by_major_then_minor() {
+        assert!(Version::new(11, 2) < Version::new(12, 1));
+        assert!(Version::new(12, 1) < Version::new(12, 2));
+        assert_eq!(Version::new(18, 0).to_string(), "18.0");
+    }
+}
@@ -0,0 +1,219 @@
+//! `tyr` optimizer statistics report
+use error::{ErrorKind, Result};
+use mimir::{flags, Connection};
+use run::Rows;
+use schema::{self, Column};
+use std::collections::BTreeMap;
+use term;
+use util;
+
+/// Table level optimizer statistics query.
+const TABLE_STATS: &str = r"SELECT TO_CHAR(NUM_ROWS), TO_CHAR(SAMPLE_SIZE),
+TO_CHAR(LAST_ANALYZED, 'YYYY-MM-DD HH24:MI:SS'), TO_CHAR(FLOOR(SYSDATE - LAST_ANALYZED)), STALE_STATS
+FROM user_tab_statistics
+WHERE table_name=:table_name AND object_type='TABLE'";
+
+/// Table level optimizer statistics.
+#[derive(Debug, Default, Getters)]
+struct TableStats {
+    /// The number of rows, if analyzed.
+    #[get]
+    num_rows: Option<String>,
+    /// The sample size used to analyze the table.
+    #[get]
+    sample_size: Option<String>,
+    /// When the table was last analyzed.
+    #[get]
+    last_analyzed: Option<String>,
+    /// The age, in days, of the statistics.
+    #[get]
+    age: Option<u64>,
+    /// Has Oracle marked the statistics as stale?
+    #[get]
+    stale: bool,
+}
+
+/// Fetch the table level statistics for the given table.
+fn table_stats(conn: &Connection, table: &str) -> Result<TableStats> {
+    let (stmt, _table_name_var) = util::prepare_for_table(conn, TABLE_STATS, table)?;
+    let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+    let (found, _) = stmt.fetch()?;
+    let mut stats: TableStats = Default::default();
+
+    if found {
+        let mut values = Vec::new();
+        for i in 1..=5 {
+            let (_, data) = stmt.get_query_value(i)?;
+            values.push(if data.null() { None } else { Some(data.get_string()) });
+        }
+        stats.stale = values[4].as_ref().map_or(false, |s| s == "YES");
+        stats.age = values[3].as_ref().and_then(|a| a.parse().ok());
+        stats.last_analyzed = values[2].take();
+        stats.sample_size = values[1].take();
+        stats.num_rows = values[0].take();
+    }
+
+    stmt.close(None)?;
+    Ok(stats)
+}
+
+/// Describe any problems with the table statistics.
+fn table_warnings(stats: &TableStats, max_age: u64) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    match *stats.age() {
+        None => warnings.push("missing statistics".to_string()),
+        Some(age) if age > max_age => warnings.push(format!("stale statistics ({} days old)", age)),
+        _ => {}
+    }
+
+    if *stats.stale() {
+        warnings.push("statistics marked stale by Oracle".to_string());
+    }
+
+    warnings
+}
+
+/// Describe any problems with the column statistics.
+fn column_warnings(column: &Column, table_analyzed: bool) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if table_analyzed && column.last_analyzed().is_none() {
+        warnings.push(format!("{}: missing column statistics", column.column_name()));
+    }
+
+    if let Some(num_nulls) = *column.num_nulls() {
+        if !*column.nullable() && num_nulls > 0 {
+            warnings.push(format!(
+                "{}: NOT NULL, but statistics report {} nulls",
+                column.column_name(),
+                num_nulls
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Format an optional statistic.
+fn opt<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+/// Report the per-table and per-column optimizer statistics, flagging missing or stale
+/// statistics and `NUM_NULLS` values contradicting `NULLABLE`.
+pub fn report(conn: &Connection, tables: &BTreeMap<String, Rows>, max_age: u64) -> Result<()> {
+    let mut t = term::stdout().ok_or_else(|| ErrorKind::Stdout)?;
+
+    for (table, rows) in tables {
+        let stats = table_stats(conn, table)?;
+        let columns = schema::columns(rows, &[])?;
+
+        t.attr(term::Attr::Bold)?;
+        t.fg(term::color::GREEN)?;
+        let table_name = format!(" Table '{}' ", table);
+        writeln!(t, "{:#^80}", table_name)?;
+        t.reset()?;
+        writeln!(
+            t,
+            "rows: {}  sample: {}  analyzed: {}",
+            opt(stats.num_rows()),
+            opt(stats.sample_size()),
+            opt(stats.last_analyzed())
+        )?;
+
+        let width = columns.iter().map(|c| c.column_name().len()).max().unwrap_or(0).max(6);
+        t.attr(term::Attr::Bold)?;
+        writeln!(
+            t,
+            "{:<width$} {:>4} {:>12} {:>12} {:>10} {:<15} {:>10}",
+            "COLUMN",
+            "NULL",
+            "DISTINCT",
+            "NULLS",
+            "DENSITY",
+            "HISTOGRAM",
+            "SAMPLE",
+            width = width
+        )?;
+        t.reset()?;
+
+        let mut warnings = table_warnings(&stats, max_age);
+        for column in &columns {
+            writeln!(
+                t,
+                "{:<width$} {:>4} {:>12} {:>12} {:>10} {:<15} {:>10}",
+                column.column_name(),
+                if *column.nullable() { "Y" } else { "N" },
+                opt(column.num_distinct()),
+                opt(column.num_nulls()),
+                column.density().map_or_else(|| "-".to_string(), |d| format!("{:.6}", d)),
+                opt(column.histogram()),
+                opt(column.sample_size()),
+                width = width
+            )?;
+            warnings.extend(column_warnings(column, stats.age().is_some()));
+        }
+
+        t.fg(term::color::RED)?;
+        for warning in warnings {
+            writeln!(t, "  ! {}", warning)?;
+        }
+        t.reset()?;
+        writeln!(t, "")?;
+        t.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{column_warnings, opt, table_warnings, TableStats};
+    use schema::Column;
+
+    fn stats(age: Option<u64>, stale: bool) -> TableStats {
+        TableStats {
+            age,
+            stale,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn table_age() {
+        assert_eq!(table_warnings(&stats(None, false), 7), vec!["missing statistics"]);
+        assert!(table_warnings(&stats(Some(7), false), 7).is_empty());
+        assert_eq!(table_warnings(&stats(Some(8), false), 7), vec!["stale statistics (8 days old)"]);
+        assert!(table_warnings(&stats(Some(8), false), 30).is_empty());
+    }
+
+    #[test]
+    fn table_marked_stale() {
+        assert_eq!(table_warnings(&stats(Some(0), true), 7), vec!["statistics marked stale by Oracle"]);
+        assert_eq!(
+            table_warnings(&stats(None, true), 7),
+            vec!["missing statistics", "statistics marked stale by Oracle"]
+        );
+    }
+
+    #[test]
+    fn column_statistics() {
+        let column = Column::described("NAME", "VARCHAR2", None, None, &[]);
+        assert_eq!(column_warnings(&column, true), vec!["NAME: missing column statistics"]);
+        assert!(column_warnings(&column, false).is_empty());
+
+        let column = column.analyzed(false, Some(3), Some("2026-01-01 00:00:00"));
+        assert_eq!(column_warnings(&column, true), vec!["NAME: NOT NULL, but statistics report 3 nulls"]);
+        let column = column.analyzed(true, Some(3), Some("2026-01-01 00:00:00"));
+        assert!(column_warnings(&column, true).is_empty());
+        let column = column.analyzed(false, Some(0), Some("2026-01-01 00:00:00"));
+        assert!(column_warnings(&column, true).is_empty());
+    }
+
+    #[test]
+    fn optional_statistics() {
+        assert_eq!(opt(&Some(42)), "42");
+        assert_eq!(opt::<u64>(&None), "-");
+    }
+}
@@ -1,41 +1,1543 @@
 //! `tyr` output templates
-use error::Result;
-use inflector::cases::pascalcase::to_pascal_case;
-use inflector::cases::snakecase::to_snake_case;
+use context::Context;
+use error::{ErrorKind, Result};
+use ident;
 use mustache;
-use run::Rows;
-use std::collections::BTreeMap;
+use schema::{self, Features};
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{self, Cursor, Write};
+use term;
+use types::{self, Mapping};
 
 /// Table struct mustache template.
-const ORM_TMPL: &str = "//! ORM generated by tyr
-use error::Result;
-use mimir::Connection;
+pub const ORM_TMPL: &str = "//! ORM generated by tyr for Oracle {{version}}
+#![allow(dead_code)]
+use error::{Error, Result};
+use mimir::enums::{ODPINativeTypeNum, ODPIOracleTypeNum};
+use mimir::{flags, Connection, Context, Data, Statement, Var};
+use std::collections::BTreeSet;
+use std::error::Error as StdError;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::str;
+use std::str::FromStr;
+{{#lobs}}
+use mimir::Lob;
+use std::cmp;
+use std::io::{Read, Write};
+{{/lobs}}
+{{#timestamps}}
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use mimir::odpi::structs::ODPITimestamp;
+{{/timestamps}}
+{{#intervals}}
+use mimir::odpi::structs::{ODPIIntervalDS, ODPIIntervalYM};
+{{/intervals}}
+
+/// Fetch the value at the given position of the current row.
+fn fetch<T, F>(stmt: &Statement, pos: u32, get: F) -> Result<T>
+where
+    F: FnOnce(&Data) -> Result<T>,
+{
+    let (_, data) = stmt.get_query_value(pos)?;
+    get(&data)
+}
+
+/// Fetch the possibly null value at the given position of the current row.
+fn fetch_opt<T, F>(stmt: &Statement, pos: u32, get: F) -> Result<Option<T>>
+where
+    F: FnOnce(&Data) -> Result<T>,
+{
+    let (_, data) = stmt.get_query_value(pos)?;
+    if data.null() {
+        Ok(None)
+    } else {
+        get(&data).map(Some)
+    }
+}
+
+/// The number of rows fetched per round trip.
+pub const FETCH_ARRAY_SIZE: u32 = {{fetch_array_size}};
+
+/// A lazy iterator over the rows of an executed query, closing the statement once exhausted or
+/// failed.
+pub struct Rows<T> {
+    /// The executed statement, until exhausted or failed.
+    stmt: Option<Statement>,
+    /// An error executing the statement, yielded first.
+    error: Option<Error>,
+    /// Reads a row from the statement.
+    from_row: fn(&Statement) -> Result<T>,
+}
+
+impl<T> Rows<T> {
+    /// Iterate over the rows of the executed statement, or yield the error executing it.
+    fn new(stmt: Result<Statement>, from_row: fn(&Statement) -> Result<T>) -> Rows<T> {
+        match stmt {
+            Ok(stmt) => Rows {
+                stmt: Some(stmt),
+                error: None,
+                from_row,
+            },
+            Err(e) => Rows {
+                stmt: None,
+                error: Some(e),
+                from_row,
+            },
+        }
+    }
+
+    /// Stop iterating, closing the statement.
+    fn finish(&mut self) -> Option<Result<T>> {
+        if let Some(stmt) = self.stmt.take() {
+            if let Err(e) = stmt.close(None) {
+                return Some(Err(e.into()));
+            }
+        }
+        None
+    }
+}
+
+impl<T> Iterator for Rows<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        let row = match self.stmt {
+            Some(ref stmt) => match stmt.fetch() {
+                Ok((true, _)) => (self.from_row)(stmt),
+                Ok((false, _)) => return self.finish(),
+                Err(e) => Err(e.into()),
+            },
+            None => return None,
+        };
+
+        if row.is_err() {
+            let _ = self.finish();
+        }
+        Some(row)
+    }
+}
+
+/// The number of rows sent per round trip by array DML.
+pub const BATCH_SIZE: usize = {{batch_size}};
+
+/// A value to bind, set by the generated conversions as they would set `Data`.  Bytes are copied,
+/// as they may be built from temporaries, and the variable's buffer is only filled once it is
+/// sized.  Byte values are bound as text, so binary values are set as hex for `HEXTORAW`.
+#[derive(Default)]
+struct Bound {
+    /// The value, unless it is bytes.
+    data: Data,
+    /// The bytes of a byte value.
+    bytes: Option<Vec<u8>>,
+}
+
+impl Bound {
+    /// Set a byte value, copying it.
+    fn set_bytes(&mut self, bytes: &[u8]) {
+        self.bytes = Some(bytes.to_vec());
+    }
+
+    /// Set a binary value as hex.
+    fn set_hex(&mut self, bytes: &[u8]) {
+        self.bytes = Some(bytes.iter().flat_map(|b| format!(\"{:02X}\", b).into_bytes()).collect());
+    }
+
+    /// Set a `BOOLEAN` value.
+    fn set_bool(&mut self, v: bool) {
+        self.data.set_bool(v);
+    }
+
+    /// Set an integer value.
+    fn set_int64(&mut self, v: i64) {
+        self.data.set_int64(v);
+    }
+
+    /// Set a double precision value.
+    fn set_double(&mut self, v: f64) {
+        self.data.set_double(v);
+    }
+
+    /// Set a single precision value.
+    fn set_float(&mut self, v: f32) {
+        self.data.set_float(v);
+    }
+    {{#timestamps}}
+
+    /// Set a timestamp value.
+    fn set_timestamp(&mut self, v: ODPITimestamp) {
+        self.data.set_timestamp(v);
+    }
+    {{/timestamps}}
+    {{#intervals}}
+
+    /// Set a day to second interval value.
+    fn set_interval_ds(&mut self, v: ODPIIntervalDS) {
+        self.data.set_interval_ds(v);
+    }
+
+    /// Set a year to month interval value.
+    fn set_interval_ym(&mut self, v: ODPIIntervalYM) {
+        self.data.set_interval_ym(v);
+    }
+    {{/intervals}}
+}
+
+/// Bind the value of each row, or null, to the named placeholder as an array of the given Oracle
+/// type, so Oracle never converts it implicitly.  Byte values are sized by the column length in
+/// bytes, or the longest value if that is longer, so Oracle rather than ODPI rejects values that
+/// don't fit.  The returned variable must outlive the statement execution.
+fn bind<R, T: ?Sized, G, F>(
+    conn: &Connection,
+    stmt: &Statement,
+    name: &str,
+    oracle_type: ODPIOracleTypeNum,
+    native_type: ODPINativeTypeNum,
+    size: u32,
+    rows: &[R],
+    get: G,
+    set: F,
+) -> Result<Var>
+where
+    G: Fn(&R) -> Option<&T>,
+    F: Fn(&mut Bound, &T) -> Result<()>,
+{
+    let mut values = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut value: Bound = Default::default();
+        match get(row) {
+            Some(v) => set(&mut value, v)?,
+            None => value.data.set_null(true),
+        }
+        values.push(value);
+    }
+
+    let longest = values.iter().filter_map(|v| v.bytes.as_ref()).map(|b| b.len() as u32).max();
+    let size = longest.map_or(size, |longest| longest.max(size));
+    let var = conn.new_var(oracle_type, native_type, rows.len() as u32, size, true, false)?;
+    for (i, value) in values.iter().enumerate() {
+        match value.bytes {
+            Some(ref bytes) => {
+                let text = str::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                var.set_from_bytes(i as u32, text)?
+            }
+            None => var.set_data(i as u32, &value.data)?,
+        }
+    }
+    stmt.bind_by_name(name, &var)?;
+    Ok(var)
+}
+
+/// Bind a row count, i.e. a page offset or limit, to the named placeholder.
+fn bind_count(conn: &Connection, stmt: &Statement, name: &str, count: u64) -> Result<Var> {
+    let count = count.min(i64::max_value() as u64) as i64;
+    bind(conn, stmt, name, ODPIOracleTypeNum::Number, ODPINativeTypeNum::Int64, 0, &[count], |c| Some(c), |data, v| {
+        data.set_int64(*v);
+        Ok(())
+    })
+}
+
+/// A table whose rows can be fetched with a `Query`.
+pub trait Queryable: Sized {
+    /// The `SELECT` statement, with the select list aliased `c1, c2, ...`.
+    const QUERY_SQL: &'static str;
+    /// The comma separated select list aliases.
+    const QUERY_COLUMNS: &'static str;
+
+    /// Define the fetched columns that aren't fetched as their default types.
+    fn define(stmt: &Statement) -> Result<()>;
+
+    /// Read a row from the current row of the given statement.
+    fn from_row(stmt: &Statement) -> Result<Self>;
+}
+
+/// A typed column of the table of `R` rows, holding `T` values, for building queries.
+pub struct Column<R, T> {
+    /// The quoted column name.
+    name: &'static str,
+    /// The bind expression, with `{}` standing in for the bind variable.
+    bind_expr: &'static str,
+    /// Bind a value to the named placeholder.
+    bind: fn(&Connection, &Statement, &str, &T) -> Result<Var>,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+impl<R, T: 'static> Column<R, T> {
+    /// Compare the column with the value using the given operator.
+    fn compare(&self, op: &'static str, value: T) -> Condition<R> {
+        let bind = self.bind;
+        Condition {
+            column: self.name,
+            op,
+            bind_expr: self.bind_expr,
+            bind: Some(Box::new(move |conn: &Connection, stmt: &Statement, name: &str| bind(conn, stmt, name, &value))),
+            row: PhantomData,
+        }
+    }
+
+    /// Test the column against `NULL` using the given operator.
+    fn test(&self, op: &'static str) -> Condition<R> {
+        Condition {
+            column: self.name,
+            op,
+            bind_expr: \"\",
+            bind: None,
+            row: PhantomData,
+        }
+    }
+
+    /// The column equals the value.  Values are taken as the column's type, so a `String` column
+    /// compares with `\"x\".to_string()` rather than `\"x\"`.  A nullable column compares with the
+    /// type inside its field's `Option`, without `Some(..)`, and is tested for `NULL` with `is_null`.
+    pub fn eq(&self, value: T) -> Condition<R> {
+        self.compare(\"=\", value)
+    }
+
+    /// The column doesn't equal the value, of the column's type as with `eq`.
+    pub fn ne(&self, value: T) -> Condition<R> {
+        self.compare(\"<>\", value)
+    }
+
+    /// The column is greater than the value, of the column's type as with `eq`.
+    pub fn gt(&self, value: T) -> Condition<R> {
+        self.compare(\">\", value)
+    }
+
+    /// The column is greater than or equal to the value, of the column's type as with `eq`.
+    pub fn ge(&self, value: T) -> Condition<R> {
+        self.compare(\">=\", value)
+    }
+
+    /// The column is less than the value, of the column's type as with `eq`.
+    pub fn lt(&self, value: T) -> Condition<R> {
+        self.compare(\"<\", value)
+    }
+
+    /// The column is less than or equal to the value, of the column's type as with `eq`.
+    pub fn le(&self, value: T) -> Condition<R> {
+        self.compare(\"<=\", value)
+    }
+
+    /// The column is `NULL`.
+    pub fn is_null(&self) -> Condition<R> {
+        self.test(\"IS NULL\")
+    }
+
+    /// The column isn't `NULL`.
+    pub fn is_not_null(&self) -> Condition<R> {
+        self.test(\"IS NOT NULL\")
+    }
+
+    /// Order by the column, ascending.
+    pub fn asc(&self) -> SortOrder<R> {
+        SortOrder {
+            column: self.name,
+            direction: \"ASC\",
+            row: PhantomData,
+        }
+    }
+
+    /// Order by the column, descending.
+    pub fn desc(&self) -> SortOrder<R> {
+        SortOrder {
+            column: self.name,
+            direction: \"DESC\",
+            row: PhantomData,
+        }
+    }
+}
+
+/// A column of the table of `R` rows, whatever type it holds, i.e. to upsert on.
+pub trait AnyColumn<R> {
+    /// The quoted column name.
+    fn name(&self) -> &'static str;
+}
+
+impl<R, T> AnyColumn<R> for Column<R, T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A `WHERE` clause condition on a column of the table of `R` rows.
+pub struct Condition<R> {
+    /// The quoted column name.
+    column: &'static str,
+    /// The operator.
+    op: &'static str,
+    /// The bind expression, with `{}` standing in for the bind variable.
+    bind_expr: &'static str,
+    /// Bind the compared value to the named placeholder, unless testing against `NULL`.
+    bind: Option<Box<dyn Fn(&Connection, &Statement, &str) -> Result<Var>>>,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+impl<R> Condition<R> {
+    /// The condition SQL, binding the value to the given placeholder.
+    fn sql(&self, placeholder: &str) -> String {
+        match self.bind {
+            Some(_) => format!(\"{} {} {}\", self.column, self.op, self.bind_expr.replace(\"{}\", placeholder)),
+            None => format!(\"{} {}\", self.column, self.op),
+        }
+    }
+}
+
+/// An `ORDER BY` item on a column of the table of `R` rows.
+pub struct SortOrder<R> {
+    /// The quoted column name.
+    column: &'static str,
+    /// `ASC` or `DESC`.
+    direction: &'static str,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+/// A query of `R` rows, rendered to SQL with bind variables for every value.
+pub struct Query<R> {
+    /// The conditions, all of which must hold.
+    conditions: Vec<Condition<R>>,
+    /// The `ORDER BY` items, in order.
+    order: Vec<SortOrder<R>>,
+    /// The maximum number of rows to fetch.
+    limit: Option<u64>,
+}
+
+impl<R: Queryable> Query<R> {
+    /// A query of every row.
+    pub fn new() -> Query<R> {
+        Query {
+            conditions: Vec::new(),
+            order: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Only fetch the rows meeting the condition, as well as any earlier ones.
+    pub fn filter(mut self, condition: Condition<R>) -> Query<R> {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Order the rows by the given column, after any earlier ones.
+    pub fn order_by(mut self, order: SortOrder<R>) -> Query<R> {
+        self.order.push(order);
+        self
+    }
+
+    /// Fetch at most the given number of rows.
+    pub fn limit(mut self, limit: u64) -> Query<R> {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The query SQL.  Compared values are bound to `:q1, :q2, ...` in order, and the limit to
+    /// `:q_limit`.
+    pub fn sql(&self) -> String {
+        let mut sql = R::QUERY_SQL.to_string();
+        for (i, condition) in self.conditions.iter().enumerate() {
+            sql.push_str(if i == 0 { \" WHERE \" } else { \" AND \" });
+            sql.push_str(&condition.sql(&format!(\":q{}\", i + 1)));
+        }
+        for (i, order) in self.order.iter().enumerate() {
+            sql.push_str(if i == 0 { \" ORDER BY \" } else { \", \" });
+            sql.push_str(&format!(\"{} {}\", order.column, order.direction));
+        }
+        if self.limit.is_some() {
+            {{#fetch_first}}
+            sql.push_str(\" FETCH FIRST :q_limit ROWS ONLY\");
+            {{/fetch_first}}
+            {{^fetch_first}}
+            sql = format!(\"SELECT {} FROM ({}) WHERE ROWNUM <= :q_limit\", R::QUERY_COLUMNS, sql);
+            {{/fetch_first}}
+        }
+        sql
+    }
+
+    /// Fetch the rows from the given connection.
+    pub fn load(&self, conn: &Connection) -> Result<Vec<R>> {
+        let stmt = conn.prepare_stmt(Some(&self.sql()[..]), None, false)?;
+        stmt.set_fetch_array_size(FETCH_ARRAY_SIZE)?;
+        let mut vars = Vec::new();
+        for (i, condition) in self.conditions.iter().enumerate() {
+            if let Some(ref bind) = condition.bind {
+                vars.push(bind(conn, &stmt, &format!(\":q{}\", i + 1))?);
+            }
+        }
+        if let Some(limit) = self.limit {
+            vars.push(bind_count(conn, &stmt, \":q_limit\", limit)?);
+        }
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        R::define(&stmt)?;
+
+        let mut rows = Vec::new();
+        let (mut found, _) = stmt.fetch()?;
+        while found {
+            rows.push(R::from_row(&stmt)?);
+            let (f, _) = stmt.fetch()?;
+            found = f;
+        }
+
+        stmt.close(None)?;
+        Ok(rows)
+    }
+}
+
+/// How array DML handles rows that fail.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchMode {
+    /// Stop at the first failing row, returning an error chained to the `BatchAborted` holding its
+    /// offset and the outcome of the rows before it.
+    Abort,
+    /// Carry on past failing rows, reporting them in `Batch::errors`.
+    Continue,
+}
+
+/// A row that failed in an array DML batch.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    /// The offset of the failing row in the rows given.
+    pub offset: usize,
+    /// The Oracle error message.
+    pub message: String,
+}
+
+/// The outcome of an array DML operation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Batch {
+    /// The number of rows affected by each row given, in order.
+    pub row_counts: Vec<u64>,
+    /// The rows that failed, with `BatchMode::Continue`.
+    pub errors: Vec<BatchError>,
+}
+
+impl Batch {
+    /// The total number of rows affected.
+    pub fn row_count(&self) -> u64 {
+        self.row_counts.iter().sum()
+    }
+}
+
+/// An array DML operation stopped by a failing row with `BatchMode::Abort`, chained to the error
+/// returned.  The rows before it were applied, and are left for the caller to commit or roll back.
+#[derive(Debug)]
+pub struct BatchAborted {
+    /// The offset of the failing row in the rows given.
+    pub offset: usize,
+    /// The outcome of the rows before the failing row, where Oracle reports it.
+    pub batch: Batch,
+    /// The error executing the failing row.
+    cause: Error,
+}
+
+impl BatchAborted {
+    /// The failure of the row at the given offset of the chunk starting at the given offset of the
+    /// rows given, after the given batch of earlier chunks.  Oracle applied the rows of the chunk
+    /// before it, with the given row counts.
+    pub fn new(batch: Batch, chunk_offset: usize, row_offset: usize, row_counts: Vec<u64>, cause: Error) -> BatchAborted {
+        let mut batch = batch;
+        batch.row_counts.extend(row_counts.into_iter().take(row_offset));
+        BatchAborted {
+            offset: chunk_offset + row_offset,
+            batch,
+            cause,
+        }
+    }
+
+    /// The `BatchAborted` the given error is chained to, if any.
+    pub fn of(e: &Error) -> Option<&BatchAborted> {
+        e.1.next_error.as_ref().and_then(|next| next.downcast_ref())
+    }
+}
+
+impl fmt::Display for BatchAborted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, \"row {}: {}\", self.offset, self.cause)
+    }
+}
+
+impl StdError for BatchAborted {
+    fn description(&self) -> &str {
+        \"array DML aborted\"
+    }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.cause)
+    }
+}
+
+impl From<BatchAborted> for Error {
+    fn from(aborted: BatchAborted) -> Error {
+        let msg = aborted.to_string();
+        Error::with_chain(aborted, msg)
+    }
+}
+
+/// Execute the bound statement once for each of the given number of rows, which start at the given
+/// offset of the rows given, adding the outcome to the batch.
+fn execute_many(
+    ctxt: &Context,
+    stmt: &Statement,
+    rows: usize,
+    offset: usize,
+    mode: BatchMode,
+    batch: &mut Batch,
+) -> Result<()> {
+    let mut exec_mode = flags::DPI_MODE_EXEC_ARRAY_DML_ROWCOUNTS;
+    if mode == BatchMode::Continue {
+        exec_mode |= flags::DPI_MODE_EXEC_BATCH_ERRORS;
+    }
+    if let Err(e) = stmt.execute_many(exec_mode, rows as u32) {
+        // Without batch errors Oracle stops at the failing row, reporting its offset in the chunk.
+        let row_offset = usize::from(ctxt.get_error().offset());
+        let row_counts = stmt.get_row_counts().unwrap_or_default();
+        let batch = mem::replace(batch, Batch::default());
+        return Err(BatchAborted::new(batch, offset, row_offset, row_counts, e.into()).into());
+    }
+    batch.row_counts.extend(stmt.get_row_counts()?);
+    if mode == BatchMode::Continue {
+        for error in stmt.get_batch_errors()? {
+            batch.errors.push(BatchError {
+                offset: offset + error.offset() as usize,
+                message: error.message(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check a value measured in the given units fits the named column.
+fn check_length(column: &str, length: usize, max_length: usize, unit: &str) -> Result<()> {
+    if length > max_length {
+        let msg = format!(\"{} value is {} {} long, but the column allows {}\", column, length, unit, max_length);
+        Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// The fields modified through the setters since a row was fetched or last updated.  It takes no
+/// part in equality or hashing, so rows compare by value.
+#[derive(Clone, Debug, Default)]
+pub struct Dirty(BTreeSet<&'static str>);
+
+impl Dirty {
+    /// Record the given field as modified.
+    fn mark(&mut self, field: &'static str) {
+        self.0.insert(field);
+    }
+
+    /// Has the given field been modified?
+    fn contains(&self, field: &str) -> bool {
+        self.0.contains(field)
+    }
+
+    /// Have any fields been modified?
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Forget the modifications, i.e. once written.
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl PartialEq for Dirty {
+    fn eq(&self, _other: &Dirty) -> bool {
+        true
+    }
+}
+
+impl Eq for Dirty {}
+
+impl Hash for Dirty {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// The locator values (`ROWID` or primary key) a row was fetched with, so writes go to that row
+/// even if the fields have been modified since.  Like `Dirty`, it takes no part in equality or
+/// hashing.
+#[derive(Clone, Debug)]
+pub struct Located<T>(Option<T>);
+
+impl<T> Default for Located<T> {
+    fn default() -> Located<T> {
+        Located(None)
+    }
+}
+
+impl<T> PartialEq for Located<T> {
+    fn eq(&self, _other: &Located<T>) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Located<T> {}
+
+impl<T> Hash for Located<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Parse a value fetched as text, i.e. a `NUMBER` too wide for a native ODPI type.
+fn parse_text<T>(text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!(\"invalid value '{}': {}\", text, e)).into())
+}
+{{#timestamps}}
+
+/// Convert an ODPI timestamp to a `NaiveDateTime`, keeping fractional seconds.
+fn naive_from_odpi(ts: &ODPITimestamp) -> Result<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(i32::from(ts.year), u32::from(ts.month), u32::from(ts.day))
+        .and_then(|date| {
+            date.and_hms_nano_opt(u32::from(ts.hour), u32::from(ts.minute), u32::from(ts.second), ts.fsecond)
+        })
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, \"invalid timestamp\").into())
+}
+
+/// Convert a `NaiveDateTime` to an ODPI timestamp, keeping fractional seconds.
+fn naive_to_odpi(dt: &NaiveDateTime) -> ODPITimestamp {
+    ODPITimestamp {
+        year: dt.year() as i16,
+        month: dt.month() as u8,
+        day: dt.day() as u8,
+        hour: dt.hour() as u8,
+        minute: dt.minute() as u8,
+        second: dt.second() as u8,
+        fsecond: dt.nanosecond(),
+        tz_hour_offset: 0,
+        tz_minute_offset: 0,
+    }
+}
+
+/// Convert an ODPI timestamp with a time zone to a `DateTime<FixedOffset>`, keeping its offset.
+fn offset_from_odpi(ts: &ODPITimestamp) -> Result<DateTime<FixedOffset>> {
+    let seconds = i32::from(ts.tz_hour_offset) * 3600 + i32::from(ts.tz_minute_offset) * 60;
+    let offset = FixedOffset::east_opt(seconds)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, \"invalid time zone offset\"))?;
+    offset
+        .from_local_datetime(&naive_from_odpi(ts)?)
+        .single()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, \"invalid timestamp\").into())
+}
+
+/// Convert a `DateTime<FixedOffset>` to an ODPI timestamp with its offset.  Both offset components
+/// take the sign of the offset, as ODPI expects.
+fn offset_to_odpi(dt: &DateTime<FixedOffset>) -> ODPITimestamp {
+    let seconds = dt.offset().local_minus_utc();
+    ODPITimestamp {
+        tz_hour_offset: (seconds / 3600) as i8,
+        tz_minute_offset: (seconds % 3600 / 60) as i8,
+        ..naive_to_odpi(&dt.naive_local())
+    }
+}
+{{/timestamps}}
+{{#intervals}}
+
+/// Convert an ODPI day to second interval to a signed `chrono::Duration`.
+fn duration_from_odpi(interval: &ODPIIntervalDS) -> ::chrono::Duration {
+    ::chrono::Duration::days(i64::from(interval.days))
+        + ::chrono::Duration::hours(i64::from(interval.hours))
+        + ::chrono::Duration::minutes(i64::from(interval.minutes))
+        + ::chrono::Duration::seconds(i64::from(interval.seconds))
+        + ::chrono::Duration::nanoseconds(i64::from(interval.fseconds))
+}
+
+/// Convert a signed `chrono::Duration` to an ODPI day to second interval.  Every component takes
+/// the sign of the duration, as Oracle requires.
+fn duration_to_odpi(duration: &::chrono::Duration) -> ODPIIntervalDS {
+    let seconds = duration.num_seconds();
+    let nanos = (*duration - ::chrono::Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0);
+    ODPIIntervalDS {
+        days: (seconds / 86_400) as i32,
+        hours: (seconds % 86_400 / 3_600) as i32,
+        minutes: (seconds % 3_600 / 60) as i32,
+        seconds: (seconds % 60) as i32,
+        fseconds: nanos as i32,
+    }
+}
+
+/// A signed `INTERVAL YEAR TO MONTH` value.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct YearMonthInterval {
+    /// The total number of months, negative for negative intervals.
+    total_months: i32,
+}
+
+impl YearMonthInterval {
+    /// Create an interval of the given years and months, i.e. `new(-1, -6)` for minus eighteen
+    /// months.
+    pub fn new(years: i32, months: i32) -> YearMonthInterval {
+        YearMonthInterval {
+            total_months: years * 12 + months,
+        }
+    }
+
+    /// The whole years in the interval.
+    pub fn years(&self) -> i32 {
+        self.total_months / 12
+    }
+
+    /// The months in the interval beyond the whole years.
+    pub fn months(&self) -> i32 {
+        self.total_months % 12
+    }
+
+    /// The total number of months in the interval.
+    pub fn total_months(&self) -> i32 {
+        self.total_months
+    }
+
+    /// Convert an ODPI year to month interval.
+    fn from_odpi(interval: &ODPIIntervalYM) -> YearMonthInterval {
+        YearMonthInterval::new(interval.years, interval.months)
+    }
+
+    /// Convert to an ODPI year to month interval.
+    fn to_odpi(&self) -> ODPIIntervalYM {
+        ODPIIntervalYM {
+            years: self.years(),
+            months: self.months(),
+        }
+    }
+}
+{{/intervals}}
+{{#lobs}}
+
+/// Read the whole of the given LOB.
+fn read_lob(lob: &Lob) -> Result<Vec<u8>> {
+    let size = lob.get_size()?;
+    Ok(lob.read_bytes(1, size)?)
+}
+
+/// Convert a `mimir` error to an `io` error.
+fn io_error(e: ::mimir::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
+
+/// Chunked, streaming access to a LOB value, so large documents never have to fit in memory.
+/// Sizes are measured in characters for `CLOB` and `NCLOB` values, and in bytes otherwise.  Writes
+/// are not visible to other sessions until the connection is committed.
+pub struct LobStream {
+    /// The statement the LOB locator was fetched with.
+    _stmt: Statement,
+    /// The LOB locator.
+    lob: Lob,
+    /// Is this a character LOB?
+    character: bool,
+    /// The 1-based offset of the next read.
+    read_offset: u64,
+    /// The 1-based offset of the next write.
+    write_offset: u64,
+    /// The number of characters or bytes read from the LOB at a time.
+    chunk_size: u64,
+    /// Bytes read from the LOB, but not yet returned.
+    read_buf: Vec<u8>,
+    /// Bytes written that don't yet form a complete UTF-8 character.
+    write_buf: Vec<u8>,
+}
+
+impl LobStream {
+    /// Wrap the given LOB locator.
+    fn new(stmt: Statement, lob: Lob, character: bool) -> Result<LobStream> {
+        let chunk_size = cmp::max(u64::from(lob.get_chunk_size()?), 8192);
+        Ok(LobStream {
+            _stmt: stmt,
+            lob,
+            character,
+            read_offset: 1,
+            write_offset: 1,
+            chunk_size,
+            read_buf: Vec::new(),
+            write_buf: Vec::new(),
+        })
+    }
+
+    /// The size of the LOB, in characters for character LOBs and bytes otherwise.
+    pub fn size(&self) -> Result<u64> {
+        Ok(self.lob.get_size()?)
+    }
+
+    /// Truncate the LOB to the given size, i.e. before overwriting it with shorter content.
+    pub fn trim(&mut self, size: u64) -> Result<()> {
+        self.lob.trim(size)?;
+        self.write_offset = cmp::min(self.write_offset, size + 1);
+        Ok(())
+    }
+
+    /// The length of the given bytes, in characters (UTF-16 code units) or bytes.
+    fn units(&self, bytes: &[u8]) -> u64 {
+        if self.character {
+            String::from_utf8_lossy(bytes).chars().map(|c| c.len_utf16() as u64).sum()
+        } else {
+            bytes.len() as u64
+        }
+    }
+}
+
+impl Read for LobStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.read_buf.is_empty() {
+            let bytes = self.lob.read_bytes(self.read_offset, self.chunk_size).map_err(io_error)?;
+            self.read_offset += self.units(&bytes);
+            self.read_buf = bytes;
+        }
+        let len = cmp::min(buf.len(), self.read_buf.len());
+        buf[..len].copy_from_slice(&self.read_buf[..len]);
+        let _ = self.read_buf.drain(..len);
+        Ok(len)
+    }
+}
+
+impl Write for LobStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_buf.extend_from_slice(buf);
+        let complete = if self.character {
+            match str::from_utf8(&self.write_buf) {
+                Ok(s) => s.len(),
+                Err(e) => e.valid_up_to(),
+            }
+        } else {
+            self.write_buf.len()
+        };
+        if complete > 0 {
+            let bytes: Vec<u8> = self.write_buf.drain(..complete).collect();
+            self.lob.write_bytes(&bytes, self.write_offset).map_err(io_error)?;
+            self.write_offset += self.units(&bytes);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.write_buf.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, \"incomplete UTF-8 character written to LOB\"))
+        }
+    }
+}
+{{/lobs}}
 
 {{#tables}}/// `{{struct_name}}` ORM
-#[derive(Getters, MutGetters, Setters, {{#derives}}{{name}}{{#comma}}, {{/comma}}{{/derives}})]
+#[derive(Getters, {{#derives}}{{name}}{{#comma}}, {{/comma}}{{/derives}})]
 pub struct {{struct_name}} {
     {{#field}}
-    /// `{{field_name}}` column
-    #[get = \"pub\"]
-    #[set = \"pub\"]
-    #[get_mut = \"pub\"]
-    {{field_name}}: {{{field_type}}},
+    /// `{{field_name}}` column
+    #[get = \"pub\"]
+    {{field_name}}: {{{field_type}}},
+    {{/field}}
+    /// The fields modified since the row was fetched or last updated.
+    dirty: Dirty,
+    {{#located}}
+    /// The locator values the row was fetched with.
+    located: Located<{{{locator_type}}}>,
+    {{/located}}
+    {{#unmapped}}
+    // TODO: `{{column_name}}` ({{data_type}}) has no Rust type mapping and was skipped.  Add a
+    // `[[types]]` override to map it.
+    {{/unmapped}}
+}
+
+impl {{struct_name}} {
+    {{#field}}
+    /// Set the `{{field_name}}` field, marking it as modified.
+    pub fn set_{{field_name}}(&mut self, {{field_name}}: {{{field_type}}}) -> &mut Self {
+        self.{{field_name}} = {{field_name}};
+        self.dirty.mark(\"{{field_name}}\");
+        self
+    }
+
+    /// Mutably borrow the `{{field_name}}` field, marking it as modified.
+    pub fn {{field_name}}_mut(&mut self) -> &mut {{{field_type}}} {
+        self.dirty.mark(\"{{field_name}}\");
+        &mut self.{{field_name}}
+    }
+
+    {{/field}}
+    /// Have any fields been modified since the row was fetched or last updated?
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Check every length limited field fits its column, counting characters or bytes as the
+    /// column does.
+    pub fn validate(&self) -> Result<()> {
+        {{#limited}}
+        {{#nullable}}
+        if let Some(ref v) = self.{{field_name}} {
+            check_length(\"{{column_name}}\", {{{length_expr}}}, {{max_length}}, \"{{length_unit}}\")?;
+        }
+        {{/nullable}}
+        {{^nullable}}
+        let v = &self.{{field_name}};
+        check_length(\"{{column_name}}\", {{{length_expr}}}, {{max_length}}, \"{{length_unit}}\")?;
+        {{/nullable}}
+        {{/limited}}
+        Ok(())
+    }
+
+    {{#select}}
+    /// The `SELECT` statement used to fetch `{{struct_name}}` rows.
+    const SELECT: &'static str = {{{select_sql}}};
+
+    /// Read a `{{struct_name}}` from the current row of the given statement.
+    fn from_row(stmt: &Statement) -> Result<{{struct_name}}> {
+        {{^located}}
+        Ok({{struct_name}} {
+        {{/located}}
+        {{#located}}
+        let mut row = {{struct_name}} {
+        {{/located}}
+            {{#field}}
+            {{field_name}}: {{#nullable}}fetch_opt{{/nullable}}{{^nullable}}fetch{{/nullable}}(stmt, {{position}}, |data| {{{from_data}}})?,
+            {{/field}}
+            dirty: Default::default(),
+            {{#located}}
+            located: Default::default(),
+            {{/located}}
+        {{^located}}
+        })
+        {{/located}}
+        {{#located}}
+        };
+        row.located = Located(Some({{{locator_snapshot}}}));
+        Ok(row)
+        {{/located}}
+    }
+
+    /// Define the fetched columns that aren't fetched as their default types.
+    fn define(stmt: &Statement) -> Result<()> {
+        {{#field}}
+        {{#defined}}
+        stmt.define_value({{position}}, ODPIOracleTypeNum::{{define}}, ODPINativeTypeNum::{{native_type}}, 0, false, None)?;
+        {{/defined}}
+        {{/field}}
+        Ok(())
+    }
+
+    /// Execute the given query of `{{struct_name}}` rows, with the variables `bind` binds, ready to
+    /// fetch rows `FETCH_ARRAY_SIZE` at a time.
+    fn select<F>(conn: &Connection, sql: &str, bind: F) -> Result<Statement>
+    where
+        F: FnOnce(&Statement) -> Result<Vec<Var>>,
+    {
+        let stmt = conn.prepare_stmt(Some(sql), None, false)?;
+        stmt.set_fetch_array_size(FETCH_ARRAY_SIZE)?;
+        let _vars = bind(&stmt)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        Self::define(&stmt)?;
+        Ok(stmt)
+    }
+
+    /// Fetch the remaining rows of the executed statement, then close it.
+    fn fetch_rows(stmt: Statement) -> Result<Vec<{{struct_name}}>> {
+        let mut rows = Vec::new();
+        let (mut found, _) = stmt.fetch()?;
+
+        while found {
+            rows.push(Self::from_row(&stmt)?);
+            let (f, _) = stmt.fetch()?;
+            found = f;
+        }
+
+        stmt.close(None)?;
+        Ok(rows)
+    }
+
+    /// Fetch a vector of `{{struct_name}}` from the given connection.  By default, all rows will be
+    /// fetched.
+    pub fn fetch(conn: &Connection) -> Result<Vec<{{struct_name}}>> {
+        Self::fetch_rows(Self::select(conn, Self::SELECT, |_| Ok(Vec::new()))?)
+    }
+
+    /// Lazily fetch `{{struct_name}}` rows from the given connection, `FETCH_ARRAY_SIZE` rows per
+    /// round trip, so only those are held in memory.  Iteration ends after the first error.
+    pub fn fetch_iter(conn: &Connection) -> impl Iterator<Item = Result<{{struct_name}}>> {
+        Rows::new(Self::select(conn, Self::SELECT, |_| Ok(Vec::new())), Self::from_row)
+    }
+
+    /// Build a query of `{{struct_name}}` rows, filtered and ordered by the typed columns of
+    /// `{{module_name}}`, i.e. `{{struct_name}}::query().filter({{module_name}}::X.gt(1)).load(conn)`.
+    pub fn query() -> Query<{{struct_name}}> {
+        Query::new()
+    }
+
+    /// Fetch the page of at most `limit` `{{struct_name}}` rows that skips the first `offset` rows,
+    /// ordered by {{{page_order}}}.  Every row before the offset is still read by the database, so
+    /// prefer {{#keyed}}`fetch_after`{{/keyed}}{{^keyed}}a keyset query{{/keyed}} for deep pages.
+    pub fn fetch_page(conn: &Connection, offset: u64, limit: u64) -> Result<Vec<{{struct_name}}>> {
+        let stmt = Self::select(conn, {{{page_sql}}}, |stmt| {
+            Ok(vec![
+                bind_count(conn, stmt, \":page_offset\", offset)?,
+                {{#fetch_first}}
+                bind_count(conn, stmt, \":page_limit\", limit)?,
+                {{/fetch_first}}
+                {{^fetch_first}}
+                bind_count(conn, stmt, \":page_end\", offset.saturating_add(limit))?,
+                {{/fetch_first}}
+            ])
+        })?;
+        Self::fetch_rows(stmt)
+    }
+    {{#keyed}}
+
+    /// Fetch at most `limit` `{{struct_name}}` rows ordered by {{{page_order}}}, starting after the
+    /// row with the given key, or from the first row if `None`.  Pass the key of the last row
+    /// fetched to get the next page; unlike `fetch_page`, the cost doesn't grow with the depth.
+    pub fn fetch_after(
+        conn: &Connection,
+        last_key: Option<&{{{key_type}}}>,
+        limit: u64,
+    ) -> Result<Vec<{{struct_name}}>> {
+        let stmt = match last_key {
+            None => Self::select(conn, {{{first_page_sql}}}, |stmt| {
+                Ok(vec![bind_count(conn, stmt, \":page_limit\", limit)?])
+            })?,
+            Some(key) => Self::select(conn, {{{next_page_sql}}}, |stmt| {
+                Ok(vec![
+                    {{#keyset}}
+                    bind(
+                        conn,
+                        stmt,
+                        \":{{bind_name}}\",
+                        ODPIOracleTypeNum::{{oracle_type}},
+                        ODPINativeTypeNum::{{native_type}},
+                        {{bind_size}},
+                        &[{{{key_value}}}],
+                        |v| Some(*v),
+                        |data, v| {
+                            {{{to_data}}};
+                            Ok(())
+                        },
+                    )?,
+                    {{/keyset}}
+                    bind_count(conn, stmt, \":page_limit\", limit)?,
+                ])
+            })?,
+        };
+        Self::fetch_rows(stmt)
+    }
+    {{/keyed}}
+    {{/select}}
+    {{#lob_field}}
+
+    /// Open a streaming handle on the `{{column_name}}` LOB of this row.  Set `for_update` to lock
+    /// the row, which is required before writing to the LOB.
+    pub fn {{field_name}}_stream(&self, conn: &Connection, for_update: bool) -> Result<LobStream> {
+        let sql = if for_update { {{{select_for_update_sql}}} } else { {{{select_sql}}} };
+        let stmt = conn.prepare_stmt(Some(sql), None, false)?;
+        let _locator = Self::locate(conn, &stmt, &[self])?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        stmt.define_value(1, ODPIOracleTypeNum::{{lob_type}}, ODPINativeTypeNum::Lob, 0, false, None)?;
+        let (found, _) = stmt.fetch()?;
+        if !found {
+            return Err(io::Error::new(io::ErrorKind::NotFound, \"{{struct_name}} row not found\").into());
+        }
+        let (_, data) = stmt.get_query_value(1)?;
+        if data.null() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, \"{{column_name}} is null\").into());
+        }
+        let lob = data.get_lob();
+        LobStream::new(stmt, lob, {{#character}}true{{/character}}{{^character}}false{{/character}})
+    }
+    {{/lob_field}}
+    {{#insert}}
+
+    /// Insert this `{{struct_name}}` as a new row.  `None` fields are inserted as `NULL`, and identity
+    /// columns are left to the database.  Returns the number of rows inserted.
+    pub fn insert(&self, conn: &Connection) -> Result<u64> {
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some({{{insert_sql}}}), None, false)?;
+        {{#insertable}}
+        let _{{bind_name}} = Self::bind_{{field_name}}(conn, &stmt, &[self])?;
+        {{/insertable}}
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+    {{#select}}
+
+    /// Insert this `{{struct_name}}` as a new row, then refresh it from the inserted row, filling in
+    /// database generated values such as identities, defaults and trigger set columns.  Returns the
+    /// number of rows inserted.
+    pub fn insert_returning(&mut self, conn: &Connection) -> Result<u64> {
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some({{{insert_returning_sql}}}), None, false)?;
+        {{#insertable}}
+        let _{{bind_name}} = Self::bind_{{field_name}}(conn, &stmt, &[self])?;
+        {{/insertable}}
+        let rowid = conn.new_var(ODPIOracleTypeNum::Rowid, ODPINativeTypeNum::Rowid, 1, 0, false, false)?;
+        stmt.bind_by_name(\":rid\", &rowid)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+
+        let stmt = conn.prepare_stmt(Some({{{select_by_rowid_sql}}}), None, false)?;
+        stmt.bind_by_name(\":rid\", &rowid)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        Self::define(&stmt)?;
+        let (found, _) = stmt.fetch()?;
+        if found {
+            *self = Self::from_row(&stmt)?;
+        }
+        stmt.close(None)?;
+        Ok(count)
+    }
+    {{/select}}
+
+    /// Insert the given rows with array DML, `BATCH_SIZE` rows per round trip.  Returns the rows
+    /// inserted by each row and, with `BatchMode::Continue`, the offsets of the rows that failed.
+    /// The context the connection was made with reports the failing row with `BatchMode::Abort`.
+    pub fn insert_many(conn: &Connection, ctxt: &Context, rows: &[{{struct_name}}], mode: BatchMode) -> Result<Batch> {
+        for row in rows {
+            row.validate()?;
+        }
+        let stmt = conn.prepare_stmt(Some({{{insert_sql}}}), None, false)?;
+        let mut batch = Batch::default();
+        for (n, chunk) in rows.chunks(BATCH_SIZE).enumerate() {
+            let chunk: Vec<&Self> = chunk.iter().collect();
+            {{#insertable}}
+            let _{{bind_name}} = Self::bind_{{field_name}}(conn, &stmt, &chunk)?;
+            {{/insertable}}
+            execute_many(ctxt, &stmt, chunk.len(), n * BATCH_SIZE, mode, &mut batch)?;
+        }
+        stmt.close(None)?;
+        Ok(batch)
+    }
+    {{/insert}}
+    {{#located}}
+    {{#update}}
+
+    /// Update the row this `{{struct_name}}` was fetched from, only writing the columns modified
+    /// through the setters since.  Concurrent changes to other columns are left alone.  Returns the
+    /// number of rows updated, which is zero if no updatable column was modified.  Either way, the
+    /// modifications are forgotten.
+    pub fn update(&mut self, conn: &Connection) -> Result<u64> {
+        let mut set = Vec::new();
+        {{#settable}}
+        if self.dirty.contains(\"{{field_name}}\") {
+            set.push({{{set_sql}}});
+        }
+        {{/settable}}
+        if set.is_empty() {
+            self.dirty.clear();
+            return Ok(0);
+        }
+
+        self.validate()?;
+        let sql = [{{{update_sql}}}, &set.join(\", \"), {{{locate_sql}}}].concat();
+        let stmt = conn.prepare_stmt(Some(&sql[..]), None, false)?;
+        let mut vars = Vec::new();
+        {{#settable}}
+        if self.dirty.contains(\"{{field_name}}\") {
+            vars.push(Self::bind_{{field_name}}(conn, &stmt, &[self])?);
+        }
+        {{/settable}}
+        vars.extend(Self::locate(conn, &stmt, &[self])?);
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        self.dirty.clear();
+        Ok(count)
+    }
+
+    /// Update the rows the given `{{struct_name}}`s were fetched from with array DML, `BATCH_SIZE`
+    /// rows per round trip, writing every writable column.  Returns the rows updated by each row
+    /// and, with `BatchMode::Continue`, the offsets of the rows that failed.  The context the
+    /// connection was made with reports the failing row with `BatchMode::Abort`.
+    pub fn update_many(conn: &Connection, ctxt: &Context, rows: &[{{struct_name}}], mode: BatchMode) -> Result<Batch> {
+        for row in rows {
+            row.validate()?;
+        }
+        let stmt = conn.prepare_stmt(Some({{{update_all_sql}}}), None, false)?;
+        let mut batch = Batch::default();
+        for (n, chunk) in rows.chunks(BATCH_SIZE).enumerate() {
+            let chunk: Vec<&Self> = chunk.iter().collect();
+            {{#settable}}
+            let _{{bind_name}} = Self::bind_{{field_name}}(conn, &stmt, &chunk)?;
+            {{/settable}}
+            let _locator = Self::locate(conn, &stmt, &chunk)?;
+            execute_many(ctxt, &stmt, chunk.len(), n * BATCH_SIZE, mode, &mut batch)?;
+        }
+        stmt.close(None)?;
+        Ok(batch)
+    }
+    {{/update}}
+    {{#delete}}
+
+    /// Delete the row this `{{struct_name}}` was fetched from.  Returns the number of rows deleted.
+    pub fn delete(&self, conn: &Connection) -> Result<u64> {
+        let stmt = conn.prepare_stmt(Some({{{delete_sql}}}), None, false)?;
+        let _locator = Self::locate(conn, &stmt, &[self])?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+    {{/delete}}
+    {{/located}}
+    {{#upsert}}
+
+    /// The quoted table name.
+    const TABLE: &'static str = {{{table_sql}}};
+
+    /// The `(field, quoted column, bind expression, inserted)` of each writable field.
+    const COLUMNS: &'static [(&'static str, &'static str, &'static str, bool)] = &[
+        {{#writable_field}}
+        (\"{{field_name}}\", {{{column_sql}}}, {{{bind_expr_sql}}}, {{#inserted}}true{{/inserted}}{{^inserted}}false{{/inserted}}),
+        {{/writable_field}}
+    ];
+
+    /// The fields of each key rows can be upserted on, primary key first.
+    pub const KEYS: &'static [&'static [&'static str]] = &[
+        {{#upsert_key}}
+        &[{{{fields}}}],
+        {{/upsert_key}}
+    ];
+
+    /// The fields `upsert` updates when a row with the same first key exists.
+    pub const UPSERT_UPDATE: &'static [&'static str] = &[{{{upsert_update}}}];
+
+    /// Insert this `{{struct_name}}`, or update the existing row with the same first of `KEYS`.
+    /// Returns the number of rows merged.
+    pub fn upsert(&self, conn: &Connection) -> Result<u64> {
+        self.merge(conn, Self::KEYS[0], Self::UPSERT_UPDATE)
+    }
+
+    /// Insert this `{{struct_name}}`, or update the `update` columns of the existing row matching it
+    /// on the `key` columns, which must be one of `KEYS`.  Both are typed columns from
+    /// `{{module_name}}`.  With no `update` columns, existing rows are left alone.  Returns the number
+    /// of rows merged.
+    pub fn upsert_on(
+        &self,
+        conn: &Connection,
+        key: &[&dyn AnyColumn<Self>],
+        update: &[&dyn AnyColumn<Self>],
+    ) -> Result<u64> {
+        let fields = |columns: &[&dyn AnyColumn<Self>]| -> Result<Vec<&'static str>> {
+            let mut fields = Vec::new();
+            for column in columns {
+                match Self::COLUMNS.iter().find(|c| c.1 == column.name()) {
+                    Some(c) => fields.push(c.0),
+                    None => {
+                        let msg = format!(\"{{struct_name}} column {} isn't writable\", column.name());
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+                    }
+                }
+            }
+            Ok(fields)
+        };
+        self.merge(conn, &fields(key)?, &fields(update)?)
+    }
+
+    /// Insert this `{{struct_name}}`, or update the `update` fields of the existing row matching it on
+    /// the `key` fields, which must be one of `KEYS`.  Returns the number of rows merged.
+    fn merge(&self, conn: &Connection, key: &[&str], update: &[&str]) -> Result<u64> {
+        if !Self::KEYS.iter().any(|k| k.len() == key.len() && k.iter().all(|f| key.contains(f))) {
+            let msg = format!(\"{:?} is not a key of {{struct_name}}\", key);
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+        }
+        let column = |field: &str| {
+            Self::COLUMNS.iter().find(|c| c.0 == field).ok_or_else(|| {
+                let msg = format!(\"{{struct_name}} has no writable field '{}'\", field);
+                io::Error::new(io::ErrorKind::InvalidInput, msg)
+            })
+        };
+
+        let mut on = Vec::new();
+        for &field in key {
+            let c = column(field)?;
+            on.push(format!(\"t.{} = {}\", c.1, c.2));
+        }
+        let mut set = Vec::new();
+        for &field in update {
+            if key.contains(&field) {
+                let msg = format!(\"{{struct_name}} key field '{}' can't be updated on match\", field);
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+            }
+            let c = column(field)?;
+            set.push(format!(\"t.{} = {}\", c.1, c.2));
+        }
+        let inserted: Vec<_> = Self::COLUMNS.iter().filter(|c| c.3).collect();
+
+        let mut sql = format!(\"MERGE INTO {} t USING dual ON ({})\", Self::TABLE, on.join(\" AND \"));
+        if !set.is_empty() {
+            sql.push_str(&format!(\" WHEN MATCHED THEN UPDATE SET {}\", set.join(\", \")));
+        }
+        sql.push_str(&format!(
+            \" WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})\",
+            inserted.iter().map(|c| c.1).collect::<Vec<_>>().join(\", \"),
+            inserted.iter().map(|c| c.2).collect::<Vec<_>>().join(\", \")
+        ));
+
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some(&sql[..]), None, false)?;
+        let mut bound = BTreeSet::new();
+        let mut vars = Vec::new();
+        for field in key.iter().chain(update).chain(inserted.iter().map(|c| &c.0)) {
+            if bound.insert(*field) {
+                vars.push(Self::field_var(conn, &stmt, field, &[self])?);
+            }
+        }
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+
+    /// Bind the named field of each row to its placeholder of the given statement.
+    fn field_var(conn: &Connection, stmt: &Statement, field: &str, rows: &[&Self]) -> Result<Var> {
+        match field {
+            {{#writable_field}}
+            \"{{field_name}}\" => Self::bind_{{field_name}}(conn, stmt, rows),
+            {{/writable_field}}
+            _ => {
+                let msg = format!(\"{{struct_name}} has no writable field '{}'\", field);
+                Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into())
+            }
+        }
+    }
+    {{/upsert}}
+    {{#located}}
+
+    /// Bind the locator each row was fetched with to the locating placeholders of the given
+    /// statement.  Rows that weren't fetched are located by their current fields.
+    fn locate(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Vec<Var>> {
+        Ok(vec![
+            {{#locator}}
+            bind(
+                conn,
+                stmt,
+                \":{{bind_name}}\",
+                ODPIOracleTypeNum::{{oracle_type}},
+                ODPINativeTypeNum::{{native_type}},
+                {{bind_size}},
+                rows,
+                |row| match row.located.0 {
+                    Some(ref located) => {{{located_value}}},
+                    None => {{{bind_value}}},
+                },
+                |data, v| {
+                    {{{to_data}}};
+                    Ok(())
+                },
+            )?,
+            {{/locator}}
+        ])
+    }
+    {{/located}}
+    {{#field}}
+    {{#bindable}}
+
+    /// Bind the `{{field_name}}` field of each row to `:{{bind_name}}` of the given statement.
+    fn bind_{{field_name}}(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            \":{{bind_name}}\",
+            ODPIOracleTypeNum::{{oracle_type}},
+            ODPINativeTypeNum::{{native_type}},
+            {{bind_size}},
+            rows,
+            |row| {{{bind_value}}},
+            |data, v| {
+                {{{to_data}}};
+                Ok(())
+            },
+        )
+    }
+    {{/bindable}}
     {{/field}}
 }
+{{#select}}
 
-impl {{struct_name}} {
-    /// Fetch a vector of `{{struct_name}}` from the given connection.  By default, all rows will be
-    /// fetched.
-    pub fn fetch(conn: &Connection) -> Result<Vec<{{struct_name}}>> {
-        Ok(Vec::new())
+impl Queryable for {{struct_name}} {
+    const QUERY_SQL: &'static str = {{{query_sql}}};
+    const QUERY_COLUMNS: &'static str = {{{query_columns}}};
+
+    fn define(stmt: &Statement) -> Result<()> {
+        {{struct_name}}::define(stmt)
+    }
+
+    fn from_row(stmt: &Statement) -> Result<{{struct_name}}> {
+        {{struct_name}}::from_row(stmt)
+    }
+}
+{{/select}}
+{{#columns}}
+
+/// Typed columns of `{{struct_name}}`, for building queries and picking the keys to upsert on.
+pub mod {{module_name}} {
+    use super::*;
+    {{#comparable}}
+
+    /// The `{{column_name}}` column.
+    pub const {{const_name}}: Column<{{struct_name}}, {{{value_type}}}> = Column {
+        name: {{{column_sql}}},
+        bind_expr: {{{compare_sql}}},
+        bind: bind_{{field_name}},
+        row: PhantomData,
+    };
+
+    /// Bind a `{{column_name}}` value to the named placeholder.
+    fn bind_{{field_name}}(conn: &Connection, stmt: &Statement, name: &str, value: &{{{value_type}}}) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            name,
+            ODPIOracleTypeNum::{{oracle_type}},
+            ODPINativeTypeNum::{{native_type}},
+            {{bind_size}},
+            &[value],
+            |v| Some(*v),
+            |data, v| {
+                {{{to_data}}};
+                Ok(())
+            },
+        )
     }
+    {{/comparable}}
 }
+{{/columns}}
 {{/tables}}";
 
 /// `File` information used for mustache template.
 #[derive(Builder, Clone, Debug, Default, Deserialize, RustcEncodable, Eq, Hash, PartialEq, Serialize, Setters)]
 struct File {
+    /// The Oracle version the generated code targets.
+    #[set]
+    version: String,
+    /// Does the target version support `OFFSET ... FETCH NEXT` row limiting?
+    #[set]
+    fetch_first: bool,
+    /// The number of rows fetched per round trip.
+    #[set]
+    fetch_array_size: u32,
+    /// The number of rows sent per round trip by array DML.
+    #[set]
+    batch_size: usize,
+    /// Are there any LOB columns needing the streaming support code?
+    #[set]
+    lobs: bool,
+    /// Are there any columns needing the timestamp conversion support code?
+    #[set]
+    timestamps: bool,
+    /// Are there any columns needing the interval conversion support code?
+    #[set]
+    intervals: bool,
     /// Tables to include in this template.
     #[set]
     tables: Vec<Table>,
@@ -61,6 +1563,85 @@ struct Table {
     /// The struct fields.
     // #[get]
     field: Vec<Field>,
+    /// The primary key fields.
+    key: Vec<Field>,
+    /// The fields locating a fetched row for writes: the `ROWID` if fetched, otherwise the primary
+    /// key.
+    locator: Vec<Field>,
+    /// Can fetched rows be located for writes?
+    located: bool,
+    /// The type of the locator values a row is fetched with, i.e. `(i64, String)`.
+    locator_type: String,
+    /// The expression capturing the locator values of a fetched `row`.
+    locator_snapshot: String,
+    /// The fields set by `update`.
+    settable: Vec<Field>,
+    /// The fields bound by `insert`.
+    insertable: Vec<Field>,
+    /// The writable fields, for `upsert`.
+    writable_field: Vec<Field>,
+    /// The keys rows can be upserted on.
+    upsert_key: Vec<UpsertKey>,
+    /// The comma separated field name literals `upsert` updates by default.
+    upsert_update: String,
+    /// Can rows be upserted?
+    upsert: bool,
+    /// The quoted table name literal.
+    table_sql: String,
+    /// The fields with a length limit to validate.
+    limited: Vec<Field>,
+    /// The columns skipped for lack of a Rust type mapping.
+    unmapped: Vec<Unmapped>,
+    /// The LOB fields that get streaming accessors.
+    lob_field: Vec<LobField>,
+    /// The `SELECT` statement literal.
+    select_sql: String,
+    /// Can the target user select from the table?
+    select: bool,
+    /// Can the target user insert into the table?
+    insert: bool,
+    /// Can the target user update the table?
+    update: bool,
+    /// Can the target user delete from the table?
+    delete: bool,
+    /// The `INSERT` statement literal.
+    insert_sql: String,
+    /// The `INSERT ... RETURNING ROWID` statement literal.
+    insert_returning_sql: String,
+    /// The `SELECT` statement literal fetching the row with a given `ROWID`.
+    select_by_rowid_sql: String,
+    /// The order of paged rows, for the docs, i.e. `"EMPNO"`.
+    page_order: String,
+    /// The `SELECT` statement literal fetching a page by offset.
+    page_sql: String,
+    /// The non-null key fields rows are paged by, in order: the primary key, or else a unique key.
+    keyset: Vec<Field>,
+    /// Can rows be paged by key?
+    keyed: bool,
+    /// The Rust type of a paging key, i.e. `i64` or `(String, i64)`.
+    key_type: String,
+    /// The `SELECT` statement literal fetching the first page by key.
+    first_page_sql: String,
+    /// The `SELECT` statement literal fetching the page after a key.
+    next_page_sql: String,
+    /// Do the typed columns get a module, for queries or upserts?
+    columns: bool,
+    /// The name of the module holding the typed columns.
+    module_name: String,
+    /// The fields that get typed columns for queries.
+    comparable: Vec<Field>,
+    /// The `SELECT` statement literal with an aliased select list, for queries.
+    query_sql: String,
+    /// The comma separated select list aliases literal.
+    query_columns: String,
+    /// The `UPDATE ... SET ` statement head literal, completed by the modified columns.
+    update_sql: String,
+    /// The `UPDATE` statement literal writing every settable column.
+    update_all_sql: String,
+    /// The ` WHERE ...` literal locating a fetched row.
+    locate_sql: String,
+    /// The `DELETE` statement literal.
+    delete_sql: String,
 }
 
 /// `Field` information used for mustache template.
@@ -76,74 +1657,540 @@ struct Field {
     /// Is this field nullable?
     #[set]
     nullable: bool,
+    /// The Oracle column name.
+    #[set]
+    #[get]
+    column_name: String,
+    /// The `SELECT` list expression.
+    #[set]
+    #[get]
+    select_expr: String,
+    /// The bind variable name.
+    #[set]
+    bind_name: String,
+    /// The bind expression, i.e. `:p_doc` or `XMLTYPE(:p_doc)`.
+    #[set]
+    #[get]
+    bind_expr: String,
+    /// The `Option<&T>` expression for the value bound from `row`, i.e. `row.doc.as_ref()`.
+    #[set]
+    bind_value: String,
+    /// Can the field be bound for writes?
+    #[set]
+    #[get]
+    writable: bool,
+    /// The `SET` list item literal, i.e. `"DOC" = XMLTYPE(:p_doc)`.
+    #[set]
+    set_sql: String,
+    /// The quoted column name literal.
+    #[set]
+    column_sql: String,
+    /// The bind expression literal.
+    #[set]
+    bind_expr_sql: String,
+    /// Is the column bound by `insert`?
+    #[set]
+    inserted: bool,
+    /// The `&T` expression for the value bound from a paging `key`, i.e. `&key.0`.
+    #[set]
+    key_value: String,
+    /// The `Option<&T>` expression for the locator value bound from a row's `located` values, i.e.
+    /// `Some(&located.0)`.
+    #[set]
+    located_value: String,
+    /// The Rust type of a value, i.e. `String` for an `Option<String>` field.
+    #[set]
+    value_type: String,
+    /// The typed column constant name, i.e. `ENAME`.
+    #[set]
+    const_name: String,
+    /// The bind expression literal with `{}` standing in for the bind variable, for queries.
+    #[set]
+    compare_sql: String,
+    /// Can the column be compared in queries?
+    #[set]
+    #[get]
+    comparable: bool,
+    /// Is the column generated by the database as an identity?
+    #[set]
+    #[get]
+    identity: bool,
+    /// Does the field get a bind helper?
+    #[set]
+    bindable: bool,
+    /// The position of the column in the `SELECT` list.
+    #[set]
+    position: usize,
+    /// Is the column defined as a non-default type when fetched?
+    #[set]
+    defined: bool,
+    /// The ODPI oracle type the column is defined as.
+    #[set]
+    define: String,
+    /// The ODPI oracle type the column is bound as.
+    #[set]
+    oracle_type: String,
+    /// The ODPI native type.
+    #[set]
+    native_type: String,
+    /// The column length in bytes, sizing bound byte values.
+    #[set]
+    bind_size: u64,
+    /// Expression converting fetched `data` to the field type.
+    #[set]
+    from_data: String,
+    /// Statement setting bound `data` from `v`.
+    #[set]
+    to_data: String,
+    /// The column length limit, or zero if there's none to validate.
+    #[set]
+    #[get]
+    max_length: u64,
+    /// Expression measuring `v` in the units of `max_length`.
+    #[set]
+    length_expr: String,
+    /// The units of `max_length`, i.e. `characters`.
+    #[set]
+    length_unit: String,
+}
+
+/// `UpsertKey` information used for mustache template.
+#[derive(Builder, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, RustcEncodable, Serialize)]
+struct UpsertKey {
+    /// The comma separated field name literals of the key.
+    fields: String,
+}
+
+/// A column without a Rust type mapping, reported as a warning and as a TODO in the output.
+#[derive(Builder, Clone, Debug, Default, Deserialize, Eq, Getters, Hash, PartialEq, RustcEncodable, Serialize)]
+struct Unmapped {
+    /// The table name.
+    table_name: String,
+    /// The Oracle column name.
+    column_name: String,
+    /// The Oracle data type.
+    data_type: String,
+}
+
+/// `LobField` information used for mustache template.
+#[derive(Builder, Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, RustcEncodable, Serialize)]
+struct LobField {
+    /// The field name tag.
+    field_name: String,
+    /// The Oracle column name.
+    column_name: String,
+    /// The ODPI oracle type of the LOB.
+    lob_type: String,
+    /// Is this a character LOB?
+    character: bool,
+    /// The `SELECT` statement literal for reading the LOB locator.
+    select_sql: String,
+    /// The `SELECT ... FOR UPDATE` statement literal for writing through the LOB locator.
+    select_for_update_sql: String,
+}
+
+/// Quote an Oracle identifier.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name)
+}
+
+/// The bind variable name for the given column.  A prefix keeps it clear of reserved words, which
+/// Oracle rejects as bind names, and it is truncated to the 30 character identifier limit.  Only
+/// ASCII characters are kept, so the truncation can't split a character.
+fn bind_name(column_name: &str) -> String {
+    let mut name: String = format!("p_{}", column_name.to_lowercase())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    name.truncate(30);
+    name
+}
+
+/// Build the template field for the given column, with the given (unique) field and bind names.
+fn field(column: &schema::Column, mapping: &Mapping, position: usize, field_name: &str, bind_name: &str) -> Field {
+    let mut field: Field = Default::default();
+    let rust_type = mapping.rust_type();
+    field.set_field_name(field_name.to_string());
+    field.set_column_name(column.column_name().clone());
+    let quoted = quote(column.column_name());
+    field.set_select_expr(mapping.select_expr().map_or_else(|| quoted.clone(), |expr| expr.replace("{}", &quoted)));
+    let bind_var = format!(":{}", bind_name);
+    let bind_expr = mapping.bind_expr().map_or_else(|| bind_var.clone(), |expr| expr.replace("{}", &bind_var));
+    field.set_set_sql(format!("{:?}", format!("{} = {}", quote(column.column_name()), bind_expr)));
+    field.set_column_sql(format!("{:?}", quote(column.column_name())));
+    field.set_bind_expr_sql(format!("{:?}", bind_expr));
+    field.set_inserted(*mapping.writable() && !column.identity());
+    field.set_comparable(*mapping.writable() && *mapping.comparable());
+    field.set_compare_sql(format!("{:?}", mapping.bind_expr().unwrap_or("{}")));
+    field.set_const_name(field_name.to_uppercase());
+    field.set_value_type(rust_type.clone());
+    field.set_bind_expr(bind_expr);
+    field.set_bind_name(bind_name.to_string());
+    field.set_position(position);
+    field.set_nullable(*column.nullable());
+    field.set_bind_value(if *column.nullable() {
+        format!("row.{}.as_ref()", field_name)
+    } else {
+        format!("Some(&row.{})", field_name)
+    });
+    field.set_writable(*mapping.writable());
+    field.set_identity(*column.identity());
+    field.set_bindable(*mapping.writable());
+    if *column.nullable() {
+        field.set_field_type(format!("Option<{}>", rust_type));
+    } else {
+        field.set_field_type(rust_type.clone());
+    }
+    if let Some(define) = *mapping.define() {
+        field.set_defined(true);
+        field.set_define(define.to_string());
+    }
+    field.set_oracle_type(mapping.oracle_type().to_string());
+    field.set_native_type(mapping.native_type().to_string());
+    field.set_bind_size(column.data_length().unwrap_or(0));
+    field.set_from_data(mapping.from_data().clone());
+    field.set_to_data(mapping.to_data().clone());
+    if let Some(max_length) = *mapping.max_length() {
+        field.set_max_length(max_length);
+        field.set_length_expr(mapping.length_expr().to_string());
+        field.set_length_unit(mapping.length_unit().to_string());
+    }
+    field
+}
+
+/// Report the columns without a Rust type mapping.
+fn report(unmapped: &[Unmapped]) -> Result<()> {
+    if unmapped.is_empty() {
+        return Ok(());
+    }
+
+    let mut t = term::stdout().ok_or_else(|| ErrorKind::Stdout)?;
+    t.attr(term::Attr::Bold)?;
+    t.fg(term::color::YELLOW)?;
+    writeln!(t, "{:#^80}", " Unmapped Columns ")?;
+    t.reset()?;
+
+    for column in unmapped {
+        t.fg(term::color::YELLOW)?;
+        write!(t, "{}.{}", column.table_name, column.column_name)?;
+        t.reset()?;
+        writeln!(t, ": no Rust type mapping for {}", column.data_type)?;
+    }
+    t.flush()?;
+
+    Ok(())
+}
+
+/// Render the tables from the mustache template, reporting the columns without a Rust type mapping
+/// and, unless that is an error in strict mode, writing the generated code to stdout.
+pub fn render(table_info: &BTreeMap<String, schema::Table>, features: &Features, ctxt: &Context) -> Result<()> {
+    let (code, unmapped) = generate(table_info, features, ctxt)?;
+    report(&unmapped)?;
+    if *ctxt.strict() && !unmapped.is_empty() {
+        return Err(ErrorKind::Unmapped(unmapped.len()).into());
+    }
+    writeln!(io::stdout(), "{}", code)?;
+    Ok(())
 }
 
-/// Render a table from the mustache template.
-pub fn render(table_info: &BTreeMap<String, Rows>) -> Result<()> {
+/// Generate the code for the tables from the mustache template, along with the columns skipped for
+/// lack of a Rust type mapping.
+fn generate(
+    table_info: &BTreeMap<String, schema::Table>,
+    features: &Features,
+    ctxt: &Context,
+) -> Result<(String, Vec<Unmapped>)> {
     let template = mustache::compile_str(ORM_TMPL)?;
     let mut file: File = Default::default();
     let mut tables: Vec<Table> = Vec::new();
+    let mut lobs = false;
+    let mut supports = BTreeSet::new();
+    let mut unmapped = Vec::new();
+    let naming = ctxt.config().naming();
+    let struct_names: BTreeMap<&String, String> = table_info
+        .keys()
+        .zip(ident::unique(table_info.keys().map(|name| ident::struct_name(naming, name)).collect(), usize::MAX))
+        .collect();
+    let module_names: BTreeMap<&String, String> = struct_names
+        .keys()
+        .cloned()
+        .zip(ident::unique(struct_names.values().map(|name| ident::module_name(name)).collect(), usize::MAX))
+        .collect();
+
+    for (table_name, table_info) in table_info {
+        let privs = table_info.privileges();
+        if !(*privs.select() || *privs.insert() || *privs.update() || *privs.delete()) {
+            continue;
+        }
+        let mut columns = Vec::new();
+        let mut mappings: Vec<Mapping> = Vec::new();
+        let mut skipped = Vec::new();
+
+        for column in table_info.columns() {
+            match types::map(table_name, column, features, ctxt) {
+                Some(mapping) => {
+                    columns.push(column.clone());
+                    mappings.push(mapping);
+                }
+                None => {
+                    let skip: Unmapped = UnmappedBuilder::default()
+                        .table_name(table_name.clone())
+                        .column_name(column.column_name().clone())
+                        .data_type(column.data_type().clone())
+                        .build()?;
+                    skipped.push(skip);
+                }
+            }
+        }
+        unmapped.extend(skipped.iter().cloned());
+
+        if *ctxt.fetch_rowid() {
+            columns.push(schema::Column::rowid());
+            mappings.push(types::rowid());
+        }
+
+        let field_names = columns
+            .iter()
+            .map(|c| ident::field_name(naming, table_name, c.column_name()))
+            .collect();
+        let field_names = ident::unique_fields(field_names);
+        let mut bind_names: Vec<String> = columns.iter().map(|c| bind_name(c.column_name())).collect();
+        if *ctxt.fetch_rowid() {
+            // Column bind names are prefixed, so this can't collide.
+            bind_names.pop();
+            bind_names = ident::unique(bind_names, 30);
+            bind_names.push("rid".to_string());
+        } else {
+            bind_names = ident::unique(bind_names, 30);
+        }
 
-    for (table_name, rows) in table_info {
         let mut fields: Vec<Field> = Vec::new();
+        for (i, (column, mapping)) in columns.iter().zip(mappings.iter()).enumerate() {
+            fields.push(field(column, mapping, i + 1, &field_names[i], &bind_names[i]));
+        }
 
-        for col_info in rows.values() {
-            let mut field: Field = Default::default();
-
-            for col in col_info {
-                match &(*col.column_name())[..] {
-                    "COLUMN_NAME" => {
-                        let type_info = col.type_info();
-                        let data = if let Some(ref data) = *col.data() {
-                            data.to_string(type_info)?
-                        } else {
-                            "(null)".to_string()
-                        };
-                        field.set_field_name(to_snake_case(&data));
-                    }
-                    "DATA_TYPE" => {
-                        let type_info = col.type_info();
-                        let data = if let Some(ref data) = *col.data() {
-                            data.to_string(type_info)?
-                        } else {
-                            "(null)".to_string()
-                        };
-                        let mapped = match &data[..] {
-                            "NUMBER" => "f64",
-                            "VARCHAR2" => "String",
-                            _ => "",
-                        };
-                        field.set_field_type(mapped.to_string());
-                    }
-                    "NULLABLE" => {
-                        let type_info = col.type_info();
-                        let data = if let Some(ref data) = *col.data() {
-                            data.to_string(type_info)?
-                        } else {
-                            "(null)".to_string()
-                        };
-                        match &data[..] {
-                            "Y" => {
-                                let mut optional = String::from("Option<");
-                                optional.push_str(field.field_type());
-                                optional.push_str(">");
-                                field.set_field_type(optional);
-                                field.set_nullable(true);
-                            }
-                            "N" => {
-                                field.set_nullable(false);
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {}
+        let mut rowid = None;
+        if *ctxt.fetch_rowid() {
+            if let Some(rowid_field) = fields.last_mut() {
+                rowid_field.set_bindable(true);
+                rowid = Some(rowid_field.clone());
+            }
+        }
+
+        let key: Vec<Field> = table_info
+            .primary_key()
+            .iter()
+            .filter_map(|name| fields.iter().find(|f| f.column_name() == name).cloned())
+            .collect();
+        let mut locator = match rowid {
+            Some(rowid) => vec![rowid],
+            None if key.len() == table_info.primary_key().len() => key.clone(),
+            None => Vec::new(),
+        };
+        let locator_count = locator.len();
+        for (i, f) in locator.iter_mut().enumerate() {
+            // A lone locator is matched by reference already, a tuple's members aren't.
+            f.set_located_value(match (f.nullable, locator_count) {
+                (true, 1) => "located.as_ref()".to_string(),
+                (true, _) => format!("located.{}.as_ref()", i),
+                (false, 1) => "Some(located)".to_string(),
+                (false, _) => format!("Some(&located.{})", i),
+            });
+        }
+        let locator_type = match locator_count {
+            1 => locator[0].field_type().clone(),
+            _ => format!("({})", locator.iter().map(|f| f.field_type().clone()).collect::<Vec<String>>().join(", ")),
+        };
+        let locator_snapshot = match locator_count {
+            1 => format!("row.{}.clone()", locator[0].field_name),
+            _ => format!(
+                "({})",
+                locator.iter().map(|f| format!("row.{}.clone()", f.field_name)).collect::<Vec<String>>().join(", ")
+            ),
+        };
+        let locator_predicate = match (*ctxt.fetch_rowid(), locator.first()) {
+            (true, Some(rowid)) => format!("ROWID = {}", rowid.bind_expr()),
+            _ => locator
+                .iter()
+                .map(|f| format!("{} = {}", quote(f.column_name()), f.bind_expr()))
+                .collect::<Vec<String>>()
+                .join(" AND "),
+        };
+        let table_sql = match *table_info.owner() {
+            Some(ref owner) => format!("{}.{}", quote(owner), quote(table_name)),
+            None => quote(table_name),
+        };
+        let insertable: Vec<Field> = fields.iter().filter(|f| *f.writable() && !f.identity()).cloned().collect();
+        let insert_sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table_sql,
+            insertable.iter().map(|f| quote(f.column_name())).collect::<Vec<String>>().join(", "),
+            insertable.iter().map(|f| f.bind_expr().clone()).collect::<Vec<String>>().join(", ")
+        );
+        let settable: Vec<Field> = insertable
+            .iter()
+            .filter(|f| !locator.iter().any(|l| l.column_name() == f.column_name()))
+            .cloned()
+            .collect();
+        let writable_fields: Vec<Field> = fields.iter().filter(|f| *f.writable()).cloned().collect();
+        let mut upsert_keys: Vec<Vec<Field>> = Vec::new();
+        for names in Some(table_info.primary_key()).into_iter().chain(table_info.unique_keys()) {
+            let key_fields: Vec<Field> = names
+                .iter()
+                .filter_map(|name| writable_fields.iter().find(|f| f.column_name() == name).cloned())
+                .collect();
+            if !names.is_empty() && key_fields.len() == names.len() {
+                upsert_keys.push(key_fields);
+            }
+        }
+        let field_list = |fields: &[Field]| -> String {
+            fields.iter().map(|f| format!("{:?}", f.field_name)).collect::<Vec<String>>().join(", ")
+        };
+        let upsert_update = upsert_keys.first().map_or_else(String::new, |key| {
+            let update: Vec<Field> = insertable
+                .iter()
+                .filter(|f| !key.iter().any(|k| k.column_name() == f.column_name()))
+                .cloned()
+                .collect();
+            field_list(&update)
+        });
+        let mut upsert_key = Vec::new();
+        for key in &upsert_keys {
+            let key: UpsertKey = UpsertKeyBuilder::default().fields(field_list(key)).build()?;
+            upsert_key.push(key);
+        }
+
+        let update_sql = format!("UPDATE {} SET ", table_sql);
+        let locate_sql = format!(" WHERE {}", locator_predicate);
+        let update_all_sql = format!(
+            "{}{}{}",
+            update_sql,
+            settable
+                .iter()
+                .map(|f| format!("{} = {}", quote(f.column_name()), f.bind_expr()))
+                .collect::<Vec<String>>()
+                .join(", "),
+            locate_sql
+        );
+        let delete_sql = format!("DELETE FROM {} WHERE {}", table_sql, locator_predicate);
+
+        let limited: Vec<Field> = fields.iter().filter(|f| *f.max_length() > 0).cloned().collect();
+        let comparable: Vec<Field> = fields.iter().filter(|f| *f.comparable()).cloned().collect();
+
+        let mut lob_fields = Vec::new();
+        if !locator.is_empty() {
+            for (field, mapping) in fields.iter().zip(mappings.iter()) {
+                if let Some(lob_type) = *mapping.lob() {
+                    let sql = format!(
+                        "SELECT {} FROM {} WHERE {}",
+                        quote(field.column_name()),
+                        table_sql,
+                        locator_predicate
+                    );
+                    let lob_field: LobField = LobFieldBuilder::default()
+                        .field_name(field.field_name.clone())
+                        .column_name(field.column_name().clone())
+                        .lob_type(lob_type.to_string())
+                        .character(mapping.character_lob())
+                        .select_sql(format!("{:?}", sql))
+                        .select_for_update_sql(format!("{:?}", format!("{} FOR UPDATE", sql)))
+                        .build()?;
+                    lob_fields.push(lob_field);
                 }
             }
-            fields.push(field);
         }
+        lobs = lobs || mappings.iter().any(|m| m.lob().is_some());
+        supports.extend(mappings.iter().filter_map(|m| *m.support()));
+
+        let select_sql = format!(
+            "SELECT {} FROM {}",
+            fields.iter().map(|f| f.select_expr().clone()).collect::<Vec<String>>().join(", "),
+            table_sql
+        );
+        let select_by_rowid_sql = format!("{} WHERE ROWID = :rid", select_sql);
+
+        let mut keyset = upsert_keys.iter().find(|k| k.iter().all(|f| !f.nullable)).cloned().unwrap_or_default();
+        let page_order = if keyset.is_empty() {
+            "ROWID".to_string()
+        } else {
+            keyset.iter().map(|f| quote(f.column_name())).collect::<Vec<String>>().join(", ")
+        };
+        let key_type = match keyset.len() {
+            1 => keyset[0].field_type().clone(),
+            _ => format!("({})", keyset.iter().map(|f| f.field_type().clone()).collect::<Vec<String>>().join(", ")),
+        };
+        let key_count = keyset.len();
+        for (i, f) in keyset.iter_mut().enumerate() {
+            f.set_key_value(if key_count == 1 { "key".to_string() } else { format!("&key.{}", i) });
+        }
+        // Row value comparisons aren't supported, so `(a, b) > (:a, :b)` is expanded.
+        let after_predicate = (0..keyset.len())
+            .map(|i| {
+                let mut terms: Vec<String> = keyset[..i]
+                    .iter()
+                    .map(|f| format!("{} = {}", quote(f.column_name()), f.bind_expr()))
+                    .collect();
+                terms.push(format!("{} > {}", quote(keyset[i].column_name()), keyset[i].bind_expr()));
+                format!("({})", terms.join(" AND "))
+            })
+            .collect::<Vec<String>>()
+            .join(" OR ");
+        // The select list is aliased, so row limiting columns can be dropped by an outer query.
+        let aliases: Vec<String> = (1..=fields.len()).map(|i| format!("c{}", i)).collect();
+        let aliased_sql = format!(
+            "SELECT {} FROM {}",
+            fields
+                .iter()
+                .zip(aliases.iter())
+                .map(|(f, alias)| format!("{} {}", f.select_expr(), alias))
+                .collect::<Vec<String>>()
+                .join(", "),
+            table_sql
+        );
+        let aliases = aliases.join(", ");
+        let (page_sql, first_page_sql, next_page_sql) = if *features.fetch_first() {
+            (
+                format!(
+                    "{} ORDER BY {} OFFSET :page_offset ROWS FETCH NEXT :page_limit ROWS ONLY",
+                    select_sql, page_order
+                ),
+                format!("{} ORDER BY {} FETCH FIRST :page_limit ROWS ONLY", select_sql, page_order),
+                format!(
+                    "{} WHERE {} ORDER BY {} FETCH FIRST :page_limit ROWS ONLY",
+                    select_sql, after_predicate, page_order
+                ),
+            )
+        } else {
+            (
+                format!(
+                    "SELECT {} FROM (SELECT q.*, ROWNUM rn FROM ({} ORDER BY {}) q WHERE ROWNUM <= :page_end) \
+                     WHERE rn > :page_offset",
+                    aliases, aliased_sql, page_order
+                ),
+                format!(
+                    "SELECT {} FROM ({} ORDER BY {}) WHERE ROWNUM <= :page_limit",
+                    aliases, aliased_sql, page_order
+                ),
+                format!(
+                    "SELECT {} FROM ({} WHERE {} ORDER BY {}) WHERE ROWNUM <= :page_limit",
+                    aliases, aliased_sql, after_predicate, page_order
+                ),
+            )
+        };
 
         let mut derives = Vec::new();
-        let derive_names = vec!["Clone", "Default", "Debug", "Eq", "Hash", "PartialEq"];
+        let mut derive_names = vec!["Clone"];
+        if fields.iter().zip(mappings.iter()).all(|(f, m)| f.nullable || *m.default()) {
+            derive_names.push("Default");
+        }
+        derive_names.push("Debug");
+        if mappings.iter().all(|m| *m.eq()) {
+            derive_names.push("Eq");
+        }
+        if mappings.iter().all(|m| *m.hash()) {
+            derive_names.push("Hash");
+        }
+        derive_names.push("PartialEq");
         for (idx, derive) in derive_names.iter().enumerate() {
             let derive: Derive = DeriveBuilder::default()
                 .name(derive.to_string())
@@ -152,17 +2199,632 @@ pub fn render(table_info: &BTreeMap<String, Rows>) -> Result<()> {
             derives.push(derive);
         }
 
+        let upsert = *privs.insert() && *privs.update() && !upsert_key.is_empty();
         let table: Table = TableBuilder::default()
-            .struct_name(to_pascal_case(table_name))
+            .struct_name(struct_names[table_name].clone())
             .derives(derives)
             .field(fields)
+            .key(key)
+            .located(!locator.is_empty())
+            .locator_type(locator_type)
+            .locator_snapshot(locator_snapshot)
+            .locator(locator)
+            .settable(settable.clone())
+            .insertable(insertable.clone())
+            .upsert(upsert)
+            .upsert_key(upsert_key)
+            .upsert_update(upsert_update)
+            .writable_field(writable_fields)
+            .table_sql(format!("{:?}", table_sql))
+            .limited(limited)
+            .unmapped(skipped)
+            .lob_field(lob_fields)
+            .select_sql(format!("{:?}", select_sql))
+            .select(*privs.select())
+            .insert(*privs.insert() && !insertable.is_empty())
+            .update(*privs.update() && !settable.is_empty())
+            .delete(*privs.delete())
+            .insert_returning_sql(format!("{:?}", format!("{} RETURNING ROWID INTO :rid", insert_sql)))
+            .insert_sql(format!("{:?}", insert_sql))
+            .select_by_rowid_sql(format!("{:?}", select_by_rowid_sql))
+            .page_order(format!("`{}`", page_order))
+            .page_sql(format!("{:?}", page_sql))
+            .keyed(!keyset.is_empty())
+            .keyset(keyset)
+            .key_type(key_type)
+            .first_page_sql(format!("{:?}", first_page_sql))
+            .next_page_sql(format!("{:?}", next_page_sql))
+            .columns(*privs.select() || upsert)
+            .module_name(module_names[table_name].clone())
+            .comparable(comparable)
+            .query_sql(format!("{:?}", aliased_sql))
+            .query_columns(format!("{:?}", aliases))
+            .update_sql(format!("{:?}", update_sql))
+            .locate_sql(format!("{:?}", locate_sql))
+            .update_all_sql(format!("{:?}", update_all_sql))
+            .delete_sql(format!("{:?}", delete_sql))
             .build()?;
 
         tables.push(table);
     }
+
+    file.set_version(features.version().to_string());
+    file.set_fetch_first(*features.fetch_first());
+    file.set_batch_size(*ctxt.batch_size());
+    file.set_fetch_array_size(*ctxt.fetch_array_size());
+    file.set_lobs(lobs);
+    file.set_timestamps(supports.contains("timestamps"));
+    file.set_intervals(supports.contains("intervals"));
     file.set_tables(tables);
     let mut out = Cursor::new(Vec::new());
     template.render(&mut out, &file)?;
-    writeln!(io::stdout(), "{}", String::from_utf8(out.into_inner())?)?;
-    Ok(())
+    Ok((String::from_utf8(out.into_inner())?, unmapped))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bind_name, generate};
+    use config::{Config, TypeConfig};
+    use context::{Context, ContextBuilder};
+    use privs::Privileges;
+    use schema::{Column, Features, Table, TableBuilder, Version};
+    use std::collections::BTreeMap;
+    use toml;
+    use types::Override;
+
+    /// A context with the default settings.
+    fn context() -> ContextBuilder {
+        let mut ctxt = ContextBuilder::default();
+        let _ = ctxt.conn_string(String::new()).username(String::new()).password(String::new());
+        ctxt
+    }
+
+    /// `ORDERS`, keyed by `ORDER_ID` and unique on `CODE`.
+    fn orders() -> TableBuilder {
+        let mut table = TableBuilder::default();
+        let _ = table
+            .name("ORDERS".to_string())
+            .columns(vec![
+                Column::described("ORDER_ID", "NUMBER", Some(9), Some(0), &[]),
+                Column::described("CODE", "VARCHAR2", None, None, &[]),
+                Column::described("NOTE", "VARCHAR2", None, None, &[]).optional(),
+            ])
+            .primary_key(vec!["ORDER_ID".to_string()])
+            .unique_keys(vec![vec!["CODE".to_string()]]);
+        table
+    }
+
+    /// The code generated for the table alone.
+    fn code(table: &TableBuilder, version: Version, ctxt: &Context) -> String {
+        let table: Table = table.build().expect("valid table");
+        let mut tables = BTreeMap::new();
+        let _ = tables.insert(table.name().clone(), table);
+        generate(&tables, &Features::new(version), ctxt).expect("generated code").0
+    }
+
+    /// `ORDERS` with the given columns after its `ORDER_ID` primary key, and no unique keys.
+    fn with(columns: Vec<Column>) -> TableBuilder {
+        let mut table = orders();
+        let mut all = vec![Column::described("ORDER_ID", "NUMBER", Some(9), Some(0), &[])];
+        all.extend(columns);
+        let _ = table.columns(all).unique_keys(vec![]);
+        table
+    }
+
+    #[test]
+    fn lob_streams() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("BODY", "CLOB", None, None, &[]).optional(),
+            Column::described("SCAN", "BLOB", None, None, &[]).optional(),
+        ]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("use mimir::Lob;"));
+        assert!(out.contains("pub fn body_stream(&self, conn: &Connection, for_update: bool) -> Result<LobStream>"));
+        assert!(out.contains(r#""SELECT \"BODY\" FROM \"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id FOR UPDATE""#));
+        assert!(out.contains(r#""SELECT \"BODY\" FROM \"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id""#));
+        assert!(out.contains("ODPIOracleTypeNum::Clob, ODPINativeTypeNum::Lob"));
+        assert!(out.contains("LobStream::new(stmt, lob, true)"));
+        assert!(out.contains("pub fn scan_stream("));
+        assert!(out.contains("LobStream::new(stmt, lob, false)"));
+
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(!out.contains("use mimir::Lob;"));
+        assert!(!out.contains("LobStream"));
+    }
+
+    #[test]
+    fn granted_operations() {
+        let ctxt = context().build().expect("valid context");
+        let operations = ["pub fn fetch(", "pub fn insert(", "pub fn update(", "pub fn delete("];
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(operations.iter().all(|op| out.contains(op)));
+
+        for (i, op) in operations.iter().enumerate() {
+            let mut table = orders();
+            let _ = table.privileges(Privileges::granted(i == 0, i == 1, i == 2, i == 3));
+            let out = code(&table, Version::new(19, 0), &ctxt);
+            assert!(out.contains("pub struct Orders {"));
+            for other in &operations {
+                assert_eq!(out.contains(other), other == op, "{} granted, {} generated", op, other);
+            }
+        }
+
+        let mut table = orders();
+        let _ = table.privileges(Privileges::granted(false, false, false, false));
+        assert!(!code(&table, Version::new(19, 0), &ctxt).contains("pub struct Orders {"));
+    }
+
+    #[test]
+    fn owner_qualified() {
+        let ctxt = context().build().expect("valid context");
+        let mut table = orders();
+        let _ = table.owner(Some("APP".to_string()));
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"APP\".\"ORDERS\"""#));
+        assert!(out.contains(r#""DELETE FROM \"APP\".\"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id""#));
+        assert!(!out.contains(r#"FROM \"ORDERS\""#));
+    }
+
+    #[test]
+    fn json_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("DOC", "JSON", None, None, &[]).optional(),
+            Column::described("META", "VARCHAR2", None, None, &["META IS JSON"]).optional(),
+        ]);
+        let out = code(&table, Version::new(21, 1), &ctxt);
+        assert!(out.contains("doc: Option<::serde_json::Value>,"));
+        assert!(out.contains("meta: Option<::serde_json::Value>,"));
+        assert!(out.contains(r#"JSON_SERIALIZE(\"DOC\" RETURNING CLOB), \"META\" FROM \"ORDERS\""#));
+        assert!(out.contains("VALUES (:p_order_id, :p_doc, :p_meta)"));
+
+        // Before 21c, JSON is only a check constraint on text.
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(!out.contains("doc: "));
+        assert!(out.contains("meta: Option<::serde_json::Value>,"));
+    }
+
+    #[test]
+    fn xmltype_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![Column::described("DOC", "XMLTYPE", None, None, &[]).optional()]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("doc: Option<String>,"));
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", XMLSERIALIZE(CONTENT \"DOC\" AS CLOB) FROM \"ORDERS\"""#));
+        assert!(out.contains("VALUES (:p_order_id, XMLTYPE(:p_doc))"));
+        assert!(out.contains(r#"SET \"DOC\" = XMLTYPE(:p_doc) WHERE"#));
+    }
+
+    #[test]
+    fn number_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("QTY", "NUMBER", Some(4), Some(0), &[]),
+            Column::described("TOTAL", "NUMBER", Some(18), Some(0), &[]),
+            Column::described("SERIAL", "NUMBER", Some(38), Some(0), &[]),
+            Column::described("PRICE", "NUMBER", Some(10), Some(2), &[]),
+            Column::described("RATIO", "NUMBER", None, None, &[]),
+        ]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("order_id: i32,"));
+        assert!(out.contains("qty: i16,"));
+        assert!(out.contains("total: i64,"));
+        assert!(out.contains("serial: i128,"));
+        assert!(out.contains("price: ::bigdecimal::BigDecimal,"));
+        assert!(out.contains("ratio: f64,"));
+        assert!(out.contains("qty: fetch(stmt, 2, |data| Ok(data.get_int64() as i16))?,"));
+        assert!(out.contains("price: fetch(stmt, 5, |data| parse_text(&data.get_string()))?,"));
+    }
+
+    #[test]
+    fn datetime_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("PLACED", "DATE", None, None, &[]),
+            Column::described("SHIPPED", "TIMESTAMP(6) WITH TIME ZONE", None, None, &[]).optional(),
+            Column::described("SEEN", "TIMESTAMP(6) WITH LOCAL TIME ZONE", None, None, &[]).optional(),
+        ]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("use chrono::{"));
+        assert!(out.contains("placed: ::chrono::NaiveDateTime,"));
+        assert!(out.contains("shipped: Option<::chrono::DateTime<::chrono::FixedOffset>>,"));
+        assert!(out.contains("seen: Option<::chrono::DateTime<::chrono::Utc>>,"));
+        assert!(out.contains(r#"\"SHIPPED\", SYS_EXTRACT_UTC(\"SEEN\") FROM \"ORDERS\""#));
+        assert!(out.contains("VALUES (:p_order_id, :p_placed, :p_shipped, FROM_TZ(:p_seen, 'UTC'))"));
+
+        assert!(!code(&orders(), Version::new(19, 0), &ctxt).contains("use chrono::{"));
+    }
+
+    #[test]
+    fn interval_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("LEAD_TIME", "INTERVAL DAY(2) TO SECOND(6)", None, None, &[]),
+            Column::described("TERM", "INTERVAL YEAR(2) TO MONTH", None, None, &[]).optional(),
+        ]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("use mimir::odpi::structs::{ODPIIntervalDS, ODPIIntervalYM};"));
+        assert!(out.contains("pub struct YearMonthInterval {"));
+        assert!(out.contains("lead_time: ::chrono::Duration,"));
+        assert!(out.contains("term: Option<YearMonthInterval>,"));
+        assert!(out.contains("ODPIOracleTypeNum::IntervalDS"));
+
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(!out.contains("ODPIIntervalDS"));
+        assert!(!out.contains("YearMonthInterval"));
+    }
+
+    #[test]
+    fn binary_columns() {
+        let table = with(vec![
+            Column::described("TOKEN", "RAW", None, None, &[]).sized(16),
+            Column::described("DIGEST", "RAW", None, None, &[]).sized(32).optional(),
+        ]);
+        let ctxt = context().build().expect("valid context");
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("token: Vec<u8>,"));
+        assert!(out.contains("digest: Option<Vec<u8>>,"));
+        assert!(out.contains("VALUES (:p_order_id, HEXTORAW(:p_token), HEXTORAW(:p_digest))"));
+        assert!(out.contains("data.set_hex(v);"));
+
+        let ctxt = context().raw16_uuid(true).build().expect("valid context");
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("token: ::uuid::Uuid,"));
+        assert!(out.contains("digest: Option<Vec<u8>>,"));
+        assert!(out.contains("data.set_hex(v.as_bytes());"));
+    }
+
+    #[test]
+    fn character_columns() {
+        let table = with(vec![
+            Column::described("CODE", "CHAR", None, None, &[]).sized(8).chars(8, false),
+            Column::described("NAME", "VARCHAR2", None, None, &[]).sized(160).chars(40, true),
+            Column::described("LABEL", "NCHAR", None, None, &[]).sized(20).chars(10, false).optional(),
+        ]);
+        let ctxt = context().build().expect("valid context");
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"check_length("CODE", v.len(), 8, "bytes")?;"#));
+        assert!(out.contains(r#"check_length("NAME", v.chars().count(), 40, "characters")?;"#));
+        assert!(out.contains(r#"check_length("LABEL", v.encode_utf16().count(), 10, "characters")?;"#));
+        assert!(out.contains("code: fetch(stmt, 2, |data| Ok(data.get_string()))?,"));
+        assert!(out.contains("ODPIOracleTypeNum::NChar"));
+
+        let ctxt = context().trim_char(true).build().expect("valid context");
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("code: fetch(stmt, 2, |data| Ok(data.get_string().trim_end_matches(' ').to_string()))?,"));
+        assert!(out.contains("name: fetch(stmt, 3, |data| Ok(data.get_string()))?,"));
+    }
+
+    #[test]
+    fn row_locators() {
+        let ctxt = context().build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#""DELETE FROM \"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id""#));
+        assert!(out.contains(r#"" WHERE \"ORDER_ID\" = :p_order_id"].concat()"#));
+        assert!(!out.contains("CAST(ROWID"));
+
+        let mut lines = with(vec![Column::described("LINE_NO", "NUMBER", Some(4), Some(0), &[])]);
+        let _ = lines.primary_key(vec!["ORDER_ID".to_string(), "LINE_NO".to_string()]);
+        let out = code(&lines, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#""DELETE FROM \"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id AND \"LINE_NO\" = :p_line_no""#));
+
+        let mut unkeyed = orders();
+        let _ = unkeyed.primary_key(vec![]);
+        let out = code(&unkeyed, Version::new(19, 0), &ctxt);
+        assert!(!out.contains("pub fn update("));
+        assert!(!out.contains("pub fn delete("));
+
+        let ctxt = context().fetch_rowid(true).build().expect("valid context");
+        let out = code(&unkeyed, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", \"CODE\", \"NOTE\", CAST(ROWID AS VARCHAR2(4000)) FROM \"ORDERS\"""#));
+        assert!(out.contains(r#""DELETE FROM \"ORDERS\" WHERE ROWID = :rid""#));
+        assert!(out.contains(r#"" WHERE ROWID = :rid"].concat()"#));
+    }
+
+    #[test]
+    fn rowid_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![Column::described("SOURCE", "UROWID", None, None, &[]).optional()]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("source: Option<String>,"));
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", CAST(\"SOURCE\" AS VARCHAR2(4000)) FROM \"ORDERS\"""#));
+    }
+
+    #[test]
+    fn flag_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![
+            Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &["ACTIVE IN (0, 1)"]),
+            Column::described("PAID", "CHAR", None, None, &["PAID IN ('Y', 'N')"]).sized(1).chars(1, false),
+            Column::described("RUSH", "BOOLEAN", None, None, &[]).optional(),
+        ]);
+        let out = code(&table, Version::new(23, 4), &ctxt);
+        assert!(out.contains("active: bool,"));
+        assert!(out.contains("active: fetch(stmt, 2, |data| Ok(data.get_int64() != 0))?,"));
+        assert!(out.contains("paid: bool,"));
+        assert!(out.contains(r#"paid: fetch(stmt, 3, |data| Ok(data.get_string().eq_ignore_ascii_case("Y")))?,"#));
+        assert!(out.contains("rush: Option<bool>,"));
+        assert!(out.contains("ODPIOracleTypeNum::Boolean"));
+
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(!out.contains("rush: "));
+    }
+
+    #[test]
+    fn type_overrides() {
+        let config = "rust_type = \"::billing::Money\"\ncolumn = \"ORDERS.TOTAL\"\n\
+                      from = \"::billing::Money::from_cents\"\nto = \"::billing::Money::cents\"";
+        let config: TypeConfig = toml::from_str(config).expect("valid type override");
+        let ctxt = context()
+            .overrides(vec![Override::new(&config).expect("valid type override")])
+            .build()
+            .expect("valid context");
+        let table = with(vec![
+            Column::described("TOTAL", "NUMBER", Some(18), Some(0), &[]),
+            Column::described("TAX", "NUMBER", Some(18), Some(0), &[]),
+        ]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("total: ::billing::Money,"));
+        assert!(out.contains("total: fetch(stmt, 2, |data| (Ok(data.get_int64())).map(::billing::Money::from_cents))?,"));
+        assert!(out.contains("let v = &::billing::Money::cents(v); data.set_int64(*v);"));
+        assert!(out.contains("tax: i64,"));
+    }
+
+    #[test]
+    fn unmapped_columns() {
+        let ctxt = context().build().expect("valid context");
+        let table = with(vec![Column::described("SHAPE", "SDO_GEOMETRY", None, None, &[]).optional()]);
+        let table: Table = table.build().expect("valid table");
+        let mut tables = BTreeMap::new();
+        let _ = tables.insert(table.name().clone(), table);
+        let (out, unmapped) = generate(&tables, &Features::new(Version::new(19, 0)), &ctxt).expect("generated code");
+        assert_eq!(unmapped.len(), 1);
+        assert_eq!(
+            (&unmapped[0].table_name[..], &unmapped[0].column_name[..], &unmapped[0].data_type[..]),
+            ("ORDERS", "SHAPE", "SDO_GEOMETRY")
+        );
+        assert!(out.contains("// TODO: `SHAPE` (SDO_GEOMETRY) has no Rust type mapping and was skipped."));
+        assert!(out.contains(r#""SELECT \"ORDER_ID\" FROM \"ORDERS\"""#));
+    }
+
+    #[test]
+    fn rust_identifiers() {
+        let ctxt = context().build().expect("valid context");
+        let mut table = with(vec![
+            Column::described("TYPE", "VARCHAR2", None, None, &[]),
+            Column::described("A$B", "VARCHAR2", None, None, &[]),
+            Column::described("A#B", "VARCHAR2", None, None, &[]),
+            Column::described("2ND", "VARCHAR2", None, None, &[]),
+            Column::described("DIRTY", "VARCHAR2", None, None, &[]),
+        ]);
+        let _ = table.name("HASHER".to_string());
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("pub struct Hasher_ {"));
+        assert!(out.contains("impl Hasher_ {"));
+        assert!(out.contains("pub mod hasher {"));
+        for field in &["type_: String,", "a_b: String,", "a_b_2: String,", "c_2nd: String,", "dirty_: String,"] {
+            assert!(out.contains(field), "{} is missing", field);
+        }
+        assert!(out.contains(r#"(:p_order_id, :p_type, :p_a_b, :p_a_b_2, :p_2nd, :p_dirty)"#));
+    }
+
+    #[test]
+    fn naming_rules() {
+        let config = "[naming]\nstrip_table_prefixes = [\"TBL_\"]\nstrip_column_prefixes = [\"C_\"]\n\
+                      strip_column_suffixes = [\"_TXT\"]\nsingularize = true\n\
+                      [naming.columns]\n\"TBL_ORDERS.CODE\" = \"reference\"";
+        let config: Config = toml::from_str(config).expect("valid configuration");
+        let ctxt = context().config(config).build().expect("valid context");
+        let mut table = with(vec![
+            Column::described("CODE", "VARCHAR2", None, None, &[]),
+            Column::described("C_NOTE_TXT", "VARCHAR2", None, None, &[]).optional(),
+        ]);
+        let _ = table.name("TBL_ORDERS".to_string());
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("pub struct Order {"));
+        assert!(out.contains("reference: String,"));
+        assert!(out.contains("note: Option<String>,"));
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", \"CODE\", \"C_NOTE_TXT\" FROM \"TBL_ORDERS\"""#));
+    }
+
+    #[test]
+    fn insert_statements() {
+        let ctxt = context().build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#""INSERT INTO \"ORDERS\" (\"ORDER_ID\", \"CODE\", \"NOTE\") VALUES (:p_order_id, :p_code, :p_note)""#));
+        assert!(out.contains(r#"VALUES (:p_order_id, :p_code, :p_note) RETURNING ROWID INTO :rid""#));
+        assert!(out.contains(r#""SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\" WHERE ROWID = :rid""#));
+
+        // Refetching the inserted row needs SELECT too.
+        let mut table = orders();
+        let _ = table.privileges(Privileges::granted(false, true, false, false));
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains("pub fn insert(&self, conn: &Connection) -> Result<u64>"));
+        assert!(!out.contains("pub fn insert_returning("));
+
+        let _ = table.privileges(Privileges::granted(true, false, true, true));
+        assert!(!code(&table, Version::new(19, 0), &ctxt).contains("INSERT INTO"));
+    }
+
+    #[test]
+    fn dirty_updates() {
+        let ctxt = context().build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"self.dirty.mark("code");"#));
+        assert!(out.contains(r#"set.push("\"CODE\" = :p_code");"#));
+        assert!(out.contains(r#"set.push("\"NOTE\" = :p_note");"#));
+        assert!(!out.contains(r#"set.push("\"ORDER_ID\" = :p_order_id");"#));
+        assert!(out.contains(r#"["UPDATE \"ORDERS\" SET ", &set.join(", "), " WHERE \"ORDER_ID\" = :p_order_id"].concat()"#));
+        assert!(out.contains("self.dirty.clear();"));
+
+        // Locating by ROWID leaves the key columns updatable.
+        let ctxt = context().fetch_rowid(true).build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"set.push("\"ORDER_ID\" = :p_order_id");"#));
+        assert!(out.contains(r#"" WHERE ROWID = :rid"].concat()"#));
+    }
+
+    #[test]
+    fn locators_bind_through_expressions() {
+        let ctxt = context().build().expect("valid context");
+        let mut table = TableBuilder::default();
+        let _ = table
+            .name("TOKENS".to_string())
+            .columns(vec![
+                Column::described("TOKEN", "RAW", None, None, &[]).sized(16),
+                Column::described("DOC", "XMLTYPE", None, None, &[]).optional(),
+            ])
+            .primary_key(vec!["TOKEN".to_string()]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"set.push("\"DOC\" = XMLTYPE(:p_doc)");"#));
+        assert!(out.contains(r#"" WHERE \"TOKEN\" = HEXTORAW(:p_token)"].concat()"#));
+        assert!(out.contains(r#""DELETE FROM \"TOKENS\" WHERE \"TOKEN\" = HEXTORAW(:p_token)""#));
+    }
+
+    #[test]
+    fn merge_upserts() {
+        let ctxt = context().build().expect("valid context");
+        let mut table = with(vec![
+            Column::described("CODE", "VARCHAR2", None, None, &[]),
+            Column::described("TOKEN", "RAW", None, None, &[]).sized(16).optional(),
+        ]);
+        let _ = table.unique_keys(vec![vec!["CODE".to_string()]]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"const TABLE: &'static str = "\"ORDERS\"";"#));
+        assert!(out.contains(r#"("order_id", "\"ORDER_ID\"", ":p_order_id", true),"#));
+        assert!(out.contains(r#"("token", "\"TOKEN\"", "HEXTORAW(:p_token)", true),"#));
+        assert!(out.contains("pub const KEYS: &'static [&'static [&'static str]] = &[\n        &[\"order_id\"],\n        &[\"code\"],\n    ];"));
+        assert!(out.contains(r#"pub const UPSERT_UPDATE: &'static [&'static str] = &["code", "token"];"#));
+        assert!(out.contains(r#"format!("MERGE INTO {} t USING dual ON ({})", Self::TABLE, on.join(" AND "))"#));
+        assert!(out.contains("pub fn upsert_on("));
+        assert!(out.contains("pub const CODE: Column<Orders, String> = Column {"));
+
+        // Upserting needs both INSERT and UPDATE, but not SELECT.
+        for &(insert, update, upsert) in &[(true, false, false), (false, true, false), (true, true, true)] {
+            let _ = table.privileges(Privileges::granted(false, insert, update, false));
+            let out = code(&table, Version::new(19, 0), &ctxt);
+            assert_eq!(out.contains("pub fn upsert("), upsert);
+            assert_eq!(out.contains("pub mod orders {"), upsert);
+        }
+
+        let _ = table.privileges(Privileges::granted(true, true, true, true)).primary_key(vec![]).unique_keys(vec![]);
+        assert!(!code(&table, Version::new(19, 0), &ctxt).contains("MERGE INTO"));
+    }
+
+    #[test]
+    fn generated_fixture() {
+        let ctxt = context().build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        let generated: Vec<&str> = out.lines().map(str::trim_end).collect();
+        let fixture: Vec<&str> = include_str!("../tests/fixtures/orm.rs").lines().collect();
+        assert_eq!(generated, fixture);
+    }
+
+    #[test]
+    fn array_dml() {
+        let ctxt = context().batch_size(250).build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains("pub const BATCH_SIZE: usize = 250;"));
+        assert!(out.contains("pub fn insert_many(conn: &Connection, ctxt: &Context, rows: &[Orders], mode: BatchMode) -> Result<Batch>"));
+        assert!(out.contains("pub fn update_many(conn: &Connection, ctxt: &Context, rows: &[Orders], mode: BatchMode) -> Result<Batch>"));
+        assert!(out.contains("let row_offset = usize::from(ctxt.get_error().offset());"));
+        assert!(out.contains("return Err(BatchAborted::new(batch, offset, row_offset, row_counts, e.into()).into());"));
+
+        for &(insert, update) in &[(true, false), (false, true)] {
+            let mut table = orders();
+            let _ = table.privileges(Privileges::granted(true, insert, update, false));
+            let out = code(&table, Version::new(19, 0), &ctxt);
+            assert_eq!(out.contains("pub fn insert_many("), insert);
+            assert_eq!(out.contains("pub fn update_many("), update);
+        }
+    }
+
+    #[test]
+    fn streamed_fetches() {
+        let ctxt = context().fetch_array_size(500).build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains("pub const FETCH_ARRAY_SIZE: u32 = 500;"));
+        assert!(out.contains("stmt.set_fetch_array_size(FETCH_ARRAY_SIZE)?;"));
+        assert!(out.contains("pub fn fetch_iter(conn: &Connection) -> impl Iterator<Item = Result<Orders>> {"));
+        assert!(out.contains("Rows::new(Self::select(conn, Self::SELECT, |_| Ok(Vec::new())), Self::from_row)"));
+
+        let mut table = orders();
+        let _ = table.privileges(Privileges::granted(false, true, true, true));
+        assert!(!code(&table, Version::new(19, 0), &ctxt).contains("pub fn fetch_iter("));
+    }
+
+    #[test]
+    fn keyset_pages() {
+        let ctxt = context().build().expect("valid context");
+        let mut table = with(vec![
+            Column::described("LINE_NO", "NUMBER", Some(4), Some(0), &[]),
+            Column::described("QTY", "NUMBER", Some(9), Some(0), &[]).optional(),
+        ]);
+        let _ = table.name("LINES".to_string()).primary_key(vec!["ORDER_ID".to_string(), "LINE_NO".to_string()]);
+        let after = r#"WHERE (\"ORDER_ID\" > :p_order_id) OR (\"ORDER_ID\" = :p_order_id AND \"LINE_NO\" > :p_line_no)"#;
+
+        let out = code(&table, Version::new(12, 1), &ctxt);
+        assert!(out.contains("last_key: Option<&(i32, i16)>,"));
+        assert!(out.contains(r#"FROM \"LINES\" ORDER BY \"ORDER_ID\", \"LINE_NO\" OFFSET :page_offset ROWS FETCH NEXT :page_limit ROWS ONLY""#));
+        assert!(out.contains(r#"FROM \"LINES\" ORDER BY \"ORDER_ID\", \"LINE_NO\" FETCH FIRST :page_limit ROWS ONLY""#));
+        assert!(out.contains(&format!(r#"FROM \"LINES\" {} ORDER BY \"ORDER_ID\", \"LINE_NO\" FETCH FIRST :page_limit ROWS ONLY""#, after)));
+        assert!(!out.contains("ROWNUM"));
+
+        // Before 12c, pages are cut with ROWNUM around an aliased select list.
+        let out = code(&table, Version::new(11, 2), &ctxt);
+        let aliased = r#"SELECT \"ORDER_ID\" c1, \"LINE_NO\" c2, \"QTY\" c3 FROM \"LINES\""#;
+        assert!(out.contains(&format!(
+            r#""SELECT c1, c2, c3 FROM (SELECT q.*, ROWNUM rn FROM ({} ORDER BY \"ORDER_ID\", \"LINE_NO\") q {}) WHERE rn > :page_offset""#,
+            aliased, "WHERE ROWNUM <= :page_end"
+        )));
+        assert!(out.contains(&format!(r#""SELECT c1, c2, c3 FROM ({} ORDER BY \"ORDER_ID\", \"LINE_NO\") WHERE ROWNUM <= :page_limit""#, aliased)));
+        assert!(out.contains(&format!(r#""SELECT c1, c2, c3 FROM ({} {} ORDER BY \"ORDER_ID\", \"LINE_NO\") WHERE ROWNUM <= :page_limit""#, aliased, after)));
+        assert!(!out.contains("FETCH FIRST") && !out.contains("OFFSET :page_offset"));
+
+        // Without a key, pages are ordered by ROWID and can't be resumed after a key.
+        let _ = table.primary_key(vec![]);
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"FROM \"LINES\" ORDER BY ROWID OFFSET :page_offset ROWS FETCH NEXT :page_limit ROWS ONLY""#));
+        assert!(out.contains("pub fn fetch_page("));
+        assert!(!out.contains("pub fn fetch_after("));
+
+        let _ = table.privileges(Privileges::granted(false, true, true, true));
+        assert!(!code(&table, Version::new(19, 0), &ctxt).contains("pub fn fetch_page("));
+    }
+
+    #[test]
+    fn typed_queries() {
+        let ctxt = context().build().expect("valid context");
+        let out = code(&orders(), Version::new(19, 0), &ctxt);
+        assert!(out.contains(r#"const QUERY_SQL: &'static str = "SELECT \"ORDER_ID\" c1, \"CODE\" c2, \"NOTE\" c3 FROM \"ORDERS\"";"#));
+        assert!(out.contains(r#"const QUERY_COLUMNS: &'static str = "c1, c2, c3";"#));
+        assert!(out.contains(r#"sql.push_str(" FETCH FIRST :q_limit ROWS ONLY");"#));
+        assert!(!out.contains(r#""SELECT {} FROM ({}) WHERE ROWNUM <= :q_limit""#));
+        assert!(out.contains("pub mod orders {"));
+        assert!(out.contains("pub const ORDER_ID: Column<Orders, i32> = Column {"));
+        assert!(out.contains("pub const CODE: Column<Orders, String> = Column {"));
+        assert!(out.contains("pub const NOTE: Column<Orders, String> = Column {"));
+        assert!(out.contains("pub fn query() -> Query<Orders> {"));
+
+        let out = code(&orders(), Version::new(11, 2), &ctxt);
+        assert!(out.contains(r#"sql = format!("SELECT {} FROM ({}) WHERE ROWNUM <= :q_limit", R::QUERY_COLUMNS, sql);"#));
+        assert!(!out.contains("FETCH FIRST :q_limit"));
+
+        let mut table = orders();
+        let _ = table.privileges(Privileges::granted(false, true, true, true));
+        let out = code(&table, Version::new(19, 0), &ctxt);
+        assert!(!out.contains("pub fn query() -> Query<Orders> {"));
+        assert!(!out.contains("impl Queryable for Orders {"));
+    }
+
+    #[test]
+    fn bind_names() {
+        assert_eq!(bind_name("ORDER_ID"), "p_order_id");
+        assert_eq!(bind_name("A$B#C"), "p_a_b_c");
+        assert_eq!(bind_name("PRÉNOM"), "p_pr_nom");
+        let name = bind_name("ÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉÉ");
+        assert_eq!(name.len(), 30);
+        assert!(name.chars().all(|c| c == '_' || c == 'p'));
+    }
 }
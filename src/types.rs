@@ -0,0 +1,677 @@
+//! `tyr` Oracle to Rust type mapping
+use config::TypeConfig;
+use context::Context;
+use error::{ErrorKind, Result};
+use filter::Pattern;
+use regex::{self, Regex};
+use schema::{Column, Features};
+
+/// The default Rust type for JSON columns.
+const JSON_VALUE: &str = "::serde_json::Value";
+/// The Rust type for scaled `NUMBER` columns.
+const DECIMAL: &str = "::bigdecimal::BigDecimal";
+
+lazy_static! {
+    /// Matches an `IS JSON` check constraint condition.
+    static ref IS_JSON: Regex = Regex::new(r"(?i)\bIS\s+JSON\b").expect("invalid IS JSON regex");
+}
+
+/// How an Oracle column maps to a Rust type, along with the generated code used to fetch and bind
+/// it.
+///
+/// `from_data` is an expression converting `data: &Data` into a `Result` of the Rust type, and
+/// `to_data` is a statement setting `data: &mut Data` from `v: &` the Rust type.
+#[derive(Clone, Debug, Default, Getters)]
+pub struct Mapping {
+    /// The Rust type, i.e. `String`.
+    #[get = "pub"]
+    rust_type: String,
+    /// The ODPI oracle type the column is defined as when fetching, if not the default.
+    #[get = "pub"]
+    define: Option<&'static str>,
+    /// The `SELECT` list expression, with `{}` standing in for the quoted column name, if not just
+    /// the column.
+    #[get = "pub"]
+    select_expr: Option<&'static str>,
+    /// The bind expression, with `{}` standing in for the bind variable, if not just the variable.
+    #[get = "pub"]
+    bind_expr: Option<&'static str>,
+    /// The ODPI oracle type used when binding, i.e. `Varchar`.  Binds are typed explicitly so that
+    /// Oracle never converts the value implicitly, i.e. hex decoding bytes bound as text to `RAW`.
+    #[get = "pub"]
+    oracle_type: &'static str,
+    /// The ODPI native type used when fetching and binding, i.e. `Bytes`.
+    #[get = "pub"]
+    native_type: &'static str,
+    /// Expression converting `data` to the Rust type.
+    #[get = "pub"]
+    from_data: String,
+    /// Statement setting `data` from `v`.
+    #[get = "pub"]
+    to_data: String,
+    /// Can the column be bound for writes?
+    #[get = "pub"]
+    writable: bool,
+    /// Can the column be compared in a `WHERE` clause, i.e. it isn't a LOB, JSON or XML column?
+    #[get = "pub"]
+    comparable: bool,
+    /// The ODPI oracle type of a LOB column, used to generate streaming accessors.
+    #[get = "pub"]
+    lob: Option<&'static str>,
+    /// The support code section the generated conversions need, i.e. `timestamps`.
+    #[get = "pub"]
+    support: Option<&'static str>,
+    /// Does the Rust type implement `Eq`?
+    #[get = "pub"]
+    eq: bool,
+    /// Does the Rust type implement `Hash`?
+    #[get = "pub"]
+    hash: bool,
+    /// Does the Rust type implement `Default`?
+    #[get = "pub"]
+    default: bool,
+    /// The column length limit, if the generated validation should enforce one.
+    #[get = "pub"]
+    max_length: Option<u64>,
+    /// Expression measuring `v` in the units of `max_length`, i.e. `v.chars().count()`.
+    #[get = "pub"]
+    length_expr: &'static str,
+    /// The units of `max_length`, i.e. `characters` or `bytes`.
+    #[get = "pub"]
+    length_unit: &'static str,
+}
+
+impl Mapping {
+    /// A writable mapping with the default define.
+    fn new(
+        rust_type: &str,
+        oracle_type: &'static str,
+        native_type: &'static str,
+        from_data: &str,
+        to_data: &str,
+    ) -> Mapping {
+        Mapping {
+            rust_type: rust_type.to_string(),
+            define: None,
+            select_expr: None,
+            bind_expr: None,
+            oracle_type,
+            native_type,
+            from_data: from_data.to_string(),
+            to_data: to_data.to_string(),
+            writable: true,
+            comparable: true,
+            lob: None,
+            support: None,
+            eq: true,
+            hash: true,
+            default: true,
+            max_length: None,
+            length_expr: "",
+            length_unit: "",
+        }
+    }
+
+    /// Define the fetched column as the given ODPI oracle type.
+    fn defined_as(mut self, oracle_type: &'static str) -> Mapping {
+        self.define = Some(oracle_type);
+        self
+    }
+
+    /// Select the column through the given expression, i.e. `JSON_SERIALIZE({} RETURNING CLOB)`.
+    fn selected_as(mut self, select_expr: &'static str) -> Mapping {
+        self.select_expr = Some(select_expr);
+        self
+    }
+
+    /// Bind the value through the given expression, i.e. `XMLTYPE({})`.
+    fn bound_as(mut self, bind_expr: &'static str) -> Mapping {
+        self.bind_expr = Some(bind_expr);
+        self
+    }
+
+    /// Generate streaming accessors for the LOB column of the given ODPI oracle type.
+    fn streamed_as(mut self, oracle_type: &'static str) -> Mapping {
+        self.lob = Some(oracle_type);
+        self
+    }
+
+    /// Validate the length of values against the column length, using the column's length
+    /// semantics.  National character set columns count UTF-16 code units, as `AL16UTF16` does.
+    fn limited_by(mut self, column: &Column) -> Mapping {
+        let national = column.data_type().starts_with('N');
+        if national || column.char_semantics() {
+            self.max_length = *column.char_length();
+            self.length_expr = if national { "v.encode_utf16().count()" } else { "v.chars().count()" };
+            self.length_unit = "characters";
+        } else {
+            self.max_length = *column.data_length();
+            self.length_expr = "v.len()";
+            self.length_unit = "bytes";
+        }
+        self
+    }
+
+    /// The column can only be read.
+    fn read_only(mut self) -> Mapping {
+        self.writable = false;
+        self
+    }
+
+    /// The column can't be compared in a `WHERE` clause.
+    fn incomparable(mut self) -> Mapping {
+        self.comparable = false;
+        self
+    }
+
+    /// The generated conversions need the given support code section.
+    fn supported_by(mut self, support: &'static str) -> Mapping {
+        self.support = Some(support);
+        self
+    }
+
+    /// The Rust type implements neither `Eq` nor `Hash`, i.e. a float.
+    fn partial(mut self) -> Mapping {
+        self.eq = false;
+        self.hash = false;
+        self
+    }
+
+    /// The Rust type is a user type, only assumed to implement `Clone`, `Debug` and `PartialEq`.
+    fn opaque(mut self) -> Mapping {
+        self.eq = false;
+        self.hash = false;
+        self.default = false;
+        self
+    }
+
+    /// Is this a character LOB (measured in characters rather than bytes)?
+    pub fn character_lob(&self) -> bool {
+        match self.lob {
+            Some("Clob") | Some("NClob") => true,
+            _ => false,
+        }
+    }
+}
+
+/// The columns a type override applies to.
+#[derive(Clone, Debug)]
+enum Selector {
+    /// Columns of the given Oracle data type.
+    OracleType(String),
+    /// The given `TABLE.COLUMN`.
+    Column(String),
+    /// Columns with a name matching the pattern.
+    Pattern(Pattern),
+}
+
+/// A configured Rust type override, applied on top of the built in mapping of the matching columns.
+#[derive(Clone, Debug, Getters)]
+pub struct Override {
+    /// The columns the override applies to.
+    selector: Selector,
+    /// The Rust type.
+    #[get = "pub"]
+    rust_type: String,
+    /// Path of a `fn(Base) -> T` converting fetched values.
+    from: Option<String>,
+    /// Path of a `fn(&T) -> Base` converting bound values.
+    to: Option<String>,
+}
+
+impl Override {
+    /// Build the override from its configuration.
+    pub fn new(config: &TypeConfig) -> Result<Override> {
+        let selector = match (config.oracle_type(), config.column(), config.pattern()) {
+            (&Some(ref oracle_type), &None, &None) => Selector::OracleType(oracle_type.to_uppercase()),
+            (&None, &Some(ref column), &None) => Selector::Column(column.clone()),
+            (&None, &None, &Some(ref pattern)) => Selector::Pattern(Pattern::new(pattern)?),
+            _ => return Err(ErrorKind::TypeOverride(config.rust_type().clone()).into()),
+        };
+
+        Ok(Override {
+            selector,
+            rust_type: config.rust_type().clone(),
+            from: config.from().clone(),
+            to: config.to().clone(),
+        })
+    }
+
+    /// How specific the override is, used to pick between overrides matching the same column.
+    fn precedence(&self) -> u8 {
+        match self.selector {
+            Selector::Column(_) => 2,
+            Selector::Pattern(_) => 1,
+            Selector::OracleType(_) => 0,
+        }
+    }
+
+    /// Does the override apply to the given column of the given table?
+    fn matches(&self, table: &str, column: &Column) -> bool {
+        match self.selector {
+            Selector::OracleType(ref oracle_type) => oracle_type == column.data_type(),
+            Selector::Column(ref name) => *name == format!("{}.{}", table, column.column_name()),
+            Selector::Pattern(ref pattern) => pattern.matches(column.column_name()),
+        }
+    }
+
+    /// Convert the built in mapping to the override type.  The built in conversions still do the
+    /// fetching and binding, with `from` and `to` (or `From`) converting to and from the override.
+    fn apply(&self, base: Mapping) -> Mapping {
+        let from = self.from.clone().unwrap_or_else(|| format!("<{}>::from", self.rust_type));
+        let to = match self.to {
+            Some(ref to) => format!("{}(v)", to),
+            None => format!("<{}>::from(v.clone())", base.rust_type),
+        };
+
+        Mapping {
+            rust_type: self.rust_type.clone(),
+            from_data: format!("({}).map({})", base.from_data, from),
+            to_data: format!("let v = &{}; {}", to, base.to_data),
+            max_length: None,
+            ..base
+        }.opaque()
+    }
+}
+
+/// Is the column constrained to the given flag values, i.e. `0, 1` for `x IN (0, 1)`?  Either
+/// order of the values is accepted.
+fn is_flag(column: &Column, values: (&str, &str)) -> bool {
+    let pattern = format!(
+        r#"(?i)(^|[^\w$#"])"?{}"?\s+IN\s*\(\s*({t}\s*,\s*{f}|{f}\s*,\s*{t})\s*\)"#,
+        regex::escape(column.column_name()),
+        t = regex::escape(values.0),
+        f = regex::escape(values.1)
+    );
+    match Regex::new(&pattern) {
+        Ok(flag) => column.checks().iter().any(|check| flag.is_match(check)),
+        Err(_) => false,
+    }
+}
+
+/// Is the column constrained to hold JSON by an `IS JSON` check constraint?
+fn is_json(column: &Column) -> bool {
+    column.checks().iter().any(|check| IS_JSON.is_match(check))
+}
+
+/// A `String` mapping, fetched as text and bound as the given ODPI oracle type, i.e. `NVarchar` for
+/// the national character set.
+fn text(oracle_type: &'static str) -> Mapping {
+    Mapping::new("String", oracle_type, "Bytes", "Ok(data.get_string())", "data.set_bytes(v.as_bytes())")
+}
+
+/// A `String` mapping for fixed width `CHAR` and `NCHAR` columns, bound as the given ODPI oracle type
+/// so comparisons use blank-padded semantics.  Oracle pads values with trailing spaces, which are
+/// optionally trimmed when fetched.
+fn fixed(oracle_type: &'static str, trim: bool) -> Mapping {
+    let from_data = if trim {
+        "Ok(data.get_string().trim_end_matches(' ').to_string())"
+    } else {
+        "Ok(data.get_string())"
+    };
+    Mapping::new("String", oracle_type, "Bytes", from_data, "data.set_bytes(v.as_bytes())")
+}
+
+/// A JSON mapping to the given `Deserialize` type, fetched and bound as text of the given ODPI oracle
+/// type.
+fn json(rust_type: &str, oracle_type: &'static str) -> Mapping {
+    let mapping = Mapping::new(
+        rust_type,
+        oracle_type,
+        "Bytes",
+        "::serde_json::from_str(&data.get_string()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())",
+        "data.set_bytes(::serde_json::to_string(v).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?.as_bytes())",
+    ).incomparable();
+
+    if rust_type == JSON_VALUE {
+        mapping.partial()
+    } else {
+        mapping.opaque()
+    }
+}
+
+/// An `f64` mapping, for unconstrained `NUMBER` and `FLOAT` columns.
+fn float() -> Mapping {
+    Mapping::new("f64", "Number", "Double", "Ok(data.get_double())", "data.set_double(*v)")
+        .defined_as("Number")
+        .partial()
+}
+
+/// A mapping fetched and bound as text, for exact numbers without a native ODPI type.  ODPI does
+/// the text conversion itself, so the session's NLS settings don't matter.
+fn exact(rust_type: &str) -> Mapping {
+    Mapping::new(
+        rust_type,
+        "Number",
+        "Bytes",
+        "parse_text(&data.get_string())",
+        "data.set_bytes(v.to_string().as_bytes())",
+    ).defined_as("Number")
+}
+
+/// The smallest integer mapping holding every value with the given number of decimal digits.
+fn integer(digits: u64) -> Mapping {
+    let rust_type = match digits {
+        0..=4 => "i16",
+        5..=9 => "i32",
+        10..=18 => "i64",
+        19..=38 => return exact("i128"),
+        // Only a negative scale takes a column past 38 digits, and past what `i128` holds.
+        _ => return exact(DECIMAL),
+    };
+
+    if rust_type == "i64" {
+        Mapping::new(rust_type, "Number", "Int64", "Ok(data.get_int64())", "data.set_int64(*v)").defined_as("Number")
+    } else {
+        Mapping::new(
+            rust_type,
+            "Number",
+            "Int64",
+            &format!("Ok(data.get_int64() as {})", rust_type),
+            "data.set_int64(i64::from(*v))",
+        ).defined_as("Number")
+    }
+}
+
+/// Map a `DATE` or `TIMESTAMP` column to a `chrono` type, keeping fractional seconds.
+///
+/// `WITH TIME ZONE` values keep the stored offset, from the ODPI timestamp's time zone offsets.
+/// `WITH LOCAL TIME ZONE` values are normalized to UTC in SQL, so the session time zone doesn't
+/// matter.
+fn datetime(data_type: &str) -> Mapping {
+    if data_type.ends_with("WITH TIME ZONE") {
+        Mapping::new(
+            "::chrono::DateTime<::chrono::FixedOffset>",
+            "TimestampTz",
+            "Timestamp",
+            "offset_from_odpi(&data.get_timestamp())",
+            "data.set_timestamp(offset_to_odpi(v))",
+        ).defined_as("TimestampTz")
+            .supported_by("timestamps")
+    } else if data_type.ends_with("WITH LOCAL TIME ZONE") {
+        Mapping::new(
+            "::chrono::DateTime<::chrono::Utc>",
+            "Timestamp",
+            "Timestamp",
+            "naive_from_odpi(&data.get_timestamp()).map(|dt| ::chrono::Utc.from_utc_datetime(&dt))",
+            "data.set_timestamp(naive_to_odpi(&v.naive_utc()))",
+        ).defined_as("Timestamp")
+            .selected_as("SYS_EXTRACT_UTC({})")
+            .bound_as("FROM_TZ({}, 'UTC')")
+            .supported_by("timestamps")
+    } else {
+        let oracle_type = if data_type == "DATE" { "Date" } else { "Timestamp" };
+        Mapping::new(
+            "::chrono::NaiveDateTime",
+            oracle_type,
+            "Timestamp",
+            "naive_from_odpi(&data.get_timestamp())",
+            "data.set_timestamp(naive_to_odpi(v))",
+        ).defined_as(oracle_type)
+            .supported_by("timestamps")
+    }
+}
+
+/// Map an `INTERVAL DAY TO SECOND` column to a signed `chrono::Duration`, or an `INTERVAL YEAR TO
+/// MONTH` column to the generated `YearMonthInterval`.
+fn interval(data_type: &str) -> Mapping {
+    if data_type.starts_with("INTERVAL DAY") {
+        Mapping::new(
+            "::chrono::Duration",
+            "IntervalDS",
+            "IntervalDS",
+            "Ok(duration_from_odpi(&data.get_interval_ds()))",
+            "data.set_interval_ds(duration_to_odpi(v))",
+        ).defined_as("IntervalDS")
+            .supported_by("intervals")
+    } else {
+        Mapping::new(
+            "YearMonthInterval",
+            "IntervalYM",
+            "IntervalYM",
+            "Ok(YearMonthInterval::from_odpi(&data.get_interval_ym()))",
+            "data.set_interval_ym(v.to_odpi())",
+        ).defined_as("IntervalYM")
+            .supported_by("intervals")
+    }
+}
+
+/// Map a `NUMBER` column by precision and scale.  Integral columns map to the smallest integer type
+/// holding every value, scaled columns to an exact decimal, and only unconstrained columns to
+/// `f64`.
+fn number(column: &Column) -> Mapping {
+    match (*column.data_precision(), *column.data_scale()) {
+        (None, None) => float(),
+        // NUMBER(*, 0) and INTEGER describe without a precision.
+        (None, Some(0)) => integer(38),
+        // A negative scale rounds to the left of the decimal point, adding digits.
+        (Some(precision), Some(scale)) if scale <= 0 => integer(precision + (-scale) as u64),
+        _ => exact(DECIMAL),
+    }
+}
+
+/// A `String` mapping fetched as a long string and bound as the given character LOB ODPI oracle type.
+fn long_text(oracle_type: &'static str) -> Mapping {
+    Mapping::new("String", oracle_type, "Bytes", "Ok(data.get_string())", "data.set_bytes(v.as_bytes())")
+        .defined_as("LongVarchar")
+        .incomparable()
+}
+
+/// A `String` mapping for the `ROWID` pseudo-column, fetched to locate the row for writes.
+pub fn rowid() -> Mapping {
+    text("Varchar").selected_as("CAST(ROWID AS VARCHAR2(4000))").read_only()
+}
+
+/// A `Vec<u8>` mapping, fetched as bytes of the given ODPI oracle type and bound as hex.
+fn bytes(oracle_type: &'static str) -> Mapping {
+    Mapping::new("Vec<u8>", "Varchar", "Bytes", "Ok(data.get_bytes())", "data.set_hex(v)")
+        .defined_as(oracle_type)
+        .bound_as("HEXTORAW({})")
+}
+
+/// A `uuid::Uuid` mapping for `RAW(16)` GUID columns, i.e. those populated by `SYS_GUID()`.
+fn uuid() -> Mapping {
+    Mapping::new(
+        "::uuid::Uuid",
+        "Varchar",
+        "Bytes",
+        "::uuid::Uuid::from_slice(&data.get_bytes()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())",
+        "data.set_hex(v.as_bytes())",
+    ).defined_as("Raw")
+    .bound_as("HEXTORAW({})")
+}
+
+/// Should the given `RAW(16)` column map to `uuid::Uuid`?
+fn is_uuid(column: &Column, ctxt: &Context) -> bool {
+    *column.data_length() == Some(16)
+        && (*ctxt.raw16_uuid() || ctxt.uuid_columns().iter().any(|p| p.matches(column.column_name())))
+}
+
+/// Map the given column of the given table to a Rust type, if it has a mapping.  The most specific
+/// configured override matching the column replaces the built in Rust type.  Columns of a native
+/// type the target version lacks have no mapping, as the generated code couldn't bind them.
+pub fn map(table: &str, column: &Column, features: &Features, ctxt: &Context) -> Option<Mapping> {
+    let base = builtin(table, column, features, ctxt)?;
+
+    Some(match most_specific(ctxt.overrides(), table, column) {
+        Some(over) => over.apply(base),
+        None => base,
+    })
+}
+
+/// The most specific of the given overrides matching the given column of the given table, if any.
+fn most_specific<'a>(overrides: &'a [Override], table: &str, column: &Column) -> Option<&'a Override> {
+    overrides
+        .iter()
+        .filter(|over| over.matches(table, column))
+        .max_by_key(|over| over.precedence())
+}
+
+/// Map the given column of the given table to the built in Rust type, if it has one.
+fn builtin(table: &str, column: &Column, features: &Features, ctxt: &Context) -> Option<Mapping> {
+    let json_type = ctxt
+        .config()
+        .json()
+        .get(&format!("{}.{}", table, column.column_name()))
+        .map_or(JSON_VALUE, |rust_type| &rust_type[..]);
+
+    let mapping = match &column.data_type()[..] {
+        "JSON" if *features.json_type() => json(json_type, "Clob")
+            .defined_as("LongVarchar")
+            .selected_as("JSON_SERIALIZE({} RETURNING CLOB)"),
+        "VARCHAR2" if is_json(column) => json(json_type, "Varchar"),
+        "CLOB" if is_json(column) => json(json_type, "Clob").defined_as("LongVarchar").streamed_as("Clob"),
+        // Binary, CLOB and object-relational XMLType storage all describe as XMLTYPE.
+        "XMLTYPE" | "SYS.XMLTYPE" => long_text("Clob")
+            .selected_as("XMLSERIALIZE(CONTENT {} AS CLOB)")
+            .bound_as("XMLTYPE({})"),
+        "BOOLEAN" if *features.boolean_type() => Mapping::new("bool", "Boolean", "Boolean", "Ok(data.get_bool())", "data.set_bool(*v)")
+            .defined_as("Boolean"),
+        "NUMBER" if *column.data_precision() == Some(1) && is_flag(column, ("1", "0")) => Mapping::new(
+            "bool",
+            "Number",
+            "Int64",
+            "Ok(data.get_int64() != 0)",
+            "data.set_int64(if *v { 1 } else { 0 })",
+        ).defined_as("Number"),
+        "NUMBER" => number(column),
+        data_type if data_type == "DATE" || data_type.starts_with("TIMESTAMP") => datetime(data_type),
+        data_type if data_type.starts_with("INTERVAL") => interval(data_type),
+        "FLOAT" => float(),
+        "BINARY_DOUBLE" => Mapping::new("f64", "NativeDouble", "Double", "Ok(data.get_double())", "data.set_double(*v)")
+            .defined_as("NativeDouble")
+            .partial(),
+        "BINARY_FLOAT" => Mapping::new(
+            "f64",
+            "NativeFloat",
+            "Float",
+            "Ok(f64::from(data.get_float()))",
+            "data.set_float(*v as f32)",
+        ).defined_as("NativeFloat")
+            .partial(),
+        "VARCHAR2" => text("Varchar").limited_by(column),
+        "NVARCHAR2" => text("NVarchar").limited_by(column),
+        "CHAR" if *column.char_length() == Some(1) && is_flag(column, ("'Y'", "'N'")) => Mapping::new(
+            "bool",
+            "Char",
+            "Bytes",
+            "Ok(data.get_string().eq_ignore_ascii_case(\"Y\"))",
+            "data.set_bytes(if *v { b\"Y\" } else { b\"N\" })",
+        ),
+        "CHAR" => fixed("Char", *ctxt.trim_char()).limited_by(column),
+        "NCHAR" => fixed("NChar", *ctxt.trim_char()).limited_by(column),
+        "CLOB" => long_text("Clob").streamed_as("Clob"),
+        "NCLOB" => long_text("NClob").streamed_as("NClob"),
+        // Logical (index-organized) and foreign rowids only convert to text through a cast.
+        "ROWID" | "UROWID" => text("Varchar").selected_as("CAST({} AS VARCHAR2(4000))"),
+        "RAW" if is_uuid(column, ctxt) => uuid(),
+        "RAW" => bytes("Raw"),
+        "LONG RAW" => bytes("LongRaw").incomparable(),
+        "BLOB" => bytes("Blob").defined_as("LongRaw").streamed_as("Blob").incomparable(),
+        "BFILE" => Mapping::new("Vec<u8>", "BFile", "Lob", "read_lob(&data.get_lob())", "")
+            .defined_as("BFile")
+            .streamed_as("BFile")
+            .read_only()
+            .incomparable(),
+        _ => return None,
+    };
+
+    Some(mapping)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{integer, is_flag, most_specific, number, Override, DECIMAL};
+    use config::TypeConfig;
+    use schema::Column;
+    use toml;
+
+    fn number_type(precision: Option<u64>, scale: Option<i64>) -> String {
+        number(&Column::described("N", "NUMBER", precision, scale, &[])).rust_type().clone()
+    }
+
+    fn type_override(config: &str) -> Override {
+        let config: TypeConfig = toml::from_str(config).expect("valid type override");
+        Override::new(&config).expect("valid type override")
+    }
+
+    #[test]
+    fn integer_widths() {
+        assert_eq!(integer(1).rust_type(), "i16");
+        assert_eq!(integer(4).rust_type(), "i16");
+        assert_eq!(integer(5).rust_type(), "i32");
+        assert_eq!(integer(9).rust_type(), "i32");
+        assert_eq!(integer(10).rust_type(), "i64");
+        assert_eq!(integer(18).rust_type(), "i64");
+        assert_eq!(integer(19).rust_type(), "i128");
+        assert_eq!(integer(38).rust_type(), "i128");
+        assert_eq!(integer(39).rust_type(), DECIMAL);
+    }
+
+    #[test]
+    fn number_precision_and_scale() {
+        assert_eq!(number_type(None, None), "f64");
+        assert_eq!(number_type(None, Some(0)), "i128");
+        assert_eq!(number_type(Some(9), Some(0)), "i32");
+        assert_eq!(number_type(Some(10), Some(2)), DECIMAL);
+    }
+
+    #[test]
+    fn number_negative_scale() {
+        // NUMBER(3, -2) holds values up to 99900, which needs 5 digits.
+        assert_eq!(number_type(Some(3), Some(-2)), "i32");
+        assert_eq!(number_type(Some(2), Some(-2)), "i16");
+        assert_eq!(number_type(Some(17), Some(-2)), "i128");
+        assert_eq!(number_type(Some(38), Some(-1)), DECIMAL);
+    }
+
+    #[test]
+    fn flags() {
+        let column = Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &["\"ACTIVE\" IN (0, 1)"]);
+        assert!(is_flag(&column, ("1", "0")));
+        let column = Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &["active in (1,0)"]);
+        assert!(is_flag(&column, ("1", "0")));
+        let column = Column::described("ACTIVE", "CHAR", None, None, &["ACTIVE IN ('Y', 'N')"]);
+        assert!(is_flag(&column, ("'Y'", "'N'")));
+        assert!(!is_flag(&column, ("1", "0")));
+    }
+
+    #[test]
+    fn not_flags() {
+        let column = Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &["IS_ACTIVE IN (0, 1)"]);
+        assert!(!is_flag(&column, ("1", "0")));
+        let column = Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &["ACTIVE IN (0, 1, 2)"]);
+        assert!(!is_flag(&column, ("1", "0")));
+        let column = Column::described("ACTIVE", "NUMBER", Some(1), Some(0), &[]);
+        assert!(!is_flag(&column, ("1", "0")));
+    }
+
+    #[test]
+    fn override_needs_one_selector() {
+        let config: TypeConfig = toml::from_str("rust_type = \"Money\"").expect("valid type override");
+        assert!(Override::new(&config).is_err());
+        let config: TypeConfig = toml::from_str("rust_type = \"Money\"\noracle_type = \"NUMBER\"\npattern = \"*_AMT\"")
+            .expect("valid type override");
+        assert!(Override::new(&config).is_err());
+    }
+
+    #[test]
+    fn override_precedence() {
+        let overrides = vec![
+            type_override("rust_type = \"ByType\"\noracle_type = \"number\""),
+            type_override("rust_type = \"ByColumn\"\ncolumn = \"ORDERS.TOTAL_AMT\""),
+            type_override("rust_type = \"ByPattern\"\npattern = \"*_AMT\""),
+        ];
+        let over = |table: &str, name: &str| {
+            let column = Column::described(name, "NUMBER", Some(10), Some(2), &[]);
+            most_specific(&overrides, table, &column).map(|over| over.rust_type().clone())
+        };
+
+        assert_eq!(over("ORDERS", "TOTAL_AMT"), Some("ByColumn".to_string()));
+        assert_eq!(over("INVOICES", "TOTAL_AMT"), Some("ByPattern".to_string()));
+        assert_eq!(over("ORDERS", "QUANTITY"), Some("ByType".to_string()));
+
+        let column = Column::described("NAME", "VARCHAR2", None, None, &[]);
+        assert!(most_specific(&overrides, "ORDERS", &column).is_none());
+    }
+}
@@ -1,8 +1,21 @@
 //! `tyr` utilities
 use error::{ErrorKind, Result};
+use mimir::enums::ODPINativeTypeNum::Bytes;
+use mimir::enums::ODPIOracleTypeNum::Varchar;
+use mimir::{Connection, Statement, Var};
 use std::collections::BTreeMap;
 use term;
 
+/// Prepare the given SQL, binding the given table name to `:table_name`.  The bound variable is
+/// returned alongside the statement so it outlives the execution.
+pub fn prepare_for_table(conn: &Connection, sql: &str, table: &str) -> Result<(Statement, Var)> {
+    let stmt = conn.prepare_stmt(Some(sql), None, false)?;
+    let table_name_var = conn.new_var(Varchar, Bytes, 1, 256, false, false)?;
+    table_name_var.set_from_bytes(0, table)?;
+    stmt.bind_by_name(":table_name", &table_name_var)?;
+    Ok((stmt, table_name_var))
+}
+
 /// Pad a string to the given length.
 fn pad_left(len: usize, s: &str) -> String {
     let mut output = String::new();
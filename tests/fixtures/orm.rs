@@ -0,0 +1,1276 @@
+//! ORM generated by tyr for Oracle 19.0
+#![allow(dead_code)]
+use error::{Error, Result};
+use mimir::enums::{ODPINativeTypeNum, ODPIOracleTypeNum};
+use mimir::{flags, Connection, Context, Data, Statement, Var};
+use std::collections::BTreeSet;
+use std::error::Error as StdError;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::str;
+use std::str::FromStr;
+
+/// Fetch the value at the given position of the current row.
+fn fetch<T, F>(stmt: &Statement, pos: u32, get: F) -> Result<T>
+where
+    F: FnOnce(&Data) -> Result<T>,
+{
+    let (_, data) = stmt.get_query_value(pos)?;
+    get(&data)
+}
+
+/// Fetch the possibly null value at the given position of the current row.
+fn fetch_opt<T, F>(stmt: &Statement, pos: u32, get: F) -> Result<Option<T>>
+where
+    F: FnOnce(&Data) -> Result<T>,
+{
+    let (_, data) = stmt.get_query_value(pos)?;
+    if data.null() {
+        Ok(None)
+    } else {
+        get(&data).map(Some)
+    }
+}
+
+/// The number of rows fetched per round trip.
+pub const FETCH_ARRAY_SIZE: u32 = 100;
+
+/// A lazy iterator over the rows of an executed query, closing the statement once exhausted or
+/// failed.
+pub struct Rows<T> {
+    /// The executed statement, until exhausted or failed.
+    stmt: Option<Statement>,
+    /// An error executing the statement, yielded first.
+    error: Option<Error>,
+    /// Reads a row from the statement.
+    from_row: fn(&Statement) -> Result<T>,
+}
+
+impl<T> Rows<T> {
+    /// Iterate over the rows of the executed statement, or yield the error executing it.
+    fn new(stmt: Result<Statement>, from_row: fn(&Statement) -> Result<T>) -> Rows<T> {
+        match stmt {
+            Ok(stmt) => Rows {
+                stmt: Some(stmt),
+                error: None,
+                from_row,
+            },
+            Err(e) => Rows {
+                stmt: None,
+                error: Some(e),
+                from_row,
+            },
+        }
+    }
+
+    /// Stop iterating, closing the statement.
+    fn finish(&mut self) -> Option<Result<T>> {
+        if let Some(stmt) = self.stmt.take() {
+            if let Err(e) = stmt.close(None) {
+                return Some(Err(e.into()));
+            }
+        }
+        None
+    }
+}
+
+impl<T> Iterator for Rows<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        let row = match self.stmt {
+            Some(ref stmt) => match stmt.fetch() {
+                Ok((true, _)) => (self.from_row)(stmt),
+                Ok((false, _)) => return self.finish(),
+                Err(e) => Err(e.into()),
+            },
+            None => return None,
+        };
+
+        if row.is_err() {
+            let _ = self.finish();
+        }
+        Some(row)
+    }
+}
+
+/// The number of rows sent per round trip by array DML.
+pub const BATCH_SIZE: usize = 1000;
+
+/// A value to bind, set by the generated conversions as they would set `Data`.  Bytes are copied,
+/// as they may be built from temporaries, and the variable's buffer is only filled once it is
+/// sized.  Byte values are bound as text, so binary values are set as hex for `HEXTORAW`.
+#[derive(Default)]
+struct Bound {
+    /// The value, unless it is bytes.
+    data: Data,
+    /// The bytes of a byte value.
+    bytes: Option<Vec<u8>>,
+}
+
+impl Bound {
+    /// Set a byte value, copying it.
+    fn set_bytes(&mut self, bytes: &[u8]) {
+        self.bytes = Some(bytes.to_vec());
+    }
+
+    /// Set a binary value as hex.
+    fn set_hex(&mut self, bytes: &[u8]) {
+        self.bytes = Some(bytes.iter().flat_map(|b| format!("{:02X}", b).into_bytes()).collect());
+    }
+
+    /// Set a `BOOLEAN` value.
+    fn set_bool(&mut self, v: bool) {
+        self.data.set_bool(v);
+    }
+
+    /// Set an integer value.
+    fn set_int64(&mut self, v: i64) {
+        self.data.set_int64(v);
+    }
+
+    /// Set a double precision value.
+    fn set_double(&mut self, v: f64) {
+        self.data.set_double(v);
+    }
+
+    /// Set a single precision value.
+    fn set_float(&mut self, v: f32) {
+        self.data.set_float(v);
+    }
+    }
+
+/// Bind the value of each row, or null, to the named placeholder as an array of the given Oracle
+/// type, so Oracle never converts it implicitly.  Byte values are sized by the column length in
+/// bytes, or the longest value if that is longer, so Oracle rather than ODPI rejects values that
+/// don't fit.  The returned variable must outlive the statement execution.
+fn bind<R, T: ?Sized, G, F>(
+    conn: &Connection,
+    stmt: &Statement,
+    name: &str,
+    oracle_type: ODPIOracleTypeNum,
+    native_type: ODPINativeTypeNum,
+    size: u32,
+    rows: &[R],
+    get: G,
+    set: F,
+) -> Result<Var>
+where
+    G: Fn(&R) -> Option<&T>,
+    F: Fn(&mut Bound, &T) -> Result<()>,
+{
+    let mut values = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut value: Bound = Default::default();
+        match get(row) {
+            Some(v) => set(&mut value, v)?,
+            None => value.data.set_null(true),
+        }
+        values.push(value);
+    }
+
+    let longest = values.iter().filter_map(|v| v.bytes.as_ref()).map(|b| b.len() as u32).max();
+    let size = longest.map_or(size, |longest| longest.max(size));
+    let var = conn.new_var(oracle_type, native_type, rows.len() as u32, size, true, false)?;
+    for (i, value) in values.iter().enumerate() {
+        match value.bytes {
+            Some(ref bytes) => {
+                let text = str::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                var.set_from_bytes(i as u32, text)?
+            }
+            None => var.set_data(i as u32, &value.data)?,
+        }
+    }
+    stmt.bind_by_name(name, &var)?;
+    Ok(var)
+}
+
+/// Bind a row count, i.e. a page offset or limit, to the named placeholder.
+fn bind_count(conn: &Connection, stmt: &Statement, name: &str, count: u64) -> Result<Var> {
+    let count = count.min(i64::max_value() as u64) as i64;
+    bind(conn, stmt, name, ODPIOracleTypeNum::Number, ODPINativeTypeNum::Int64, 0, &[count], |c| Some(c), |data, v| {
+        data.set_int64(*v);
+        Ok(())
+    })
+}
+
+/// A table whose rows can be fetched with a `Query`.
+pub trait Queryable: Sized {
+    /// The `SELECT` statement, with the select list aliased `c1, c2, ...`.
+    const QUERY_SQL: &'static str;
+    /// The comma separated select list aliases.
+    const QUERY_COLUMNS: &'static str;
+
+    /// Define the fetched columns that aren't fetched as their default types.
+    fn define(stmt: &Statement) -> Result<()>;
+
+    /// Read a row from the current row of the given statement.
+    fn from_row(stmt: &Statement) -> Result<Self>;
+}
+
+/// A typed column of the table of `R` rows, holding `T` values, for building queries.
+pub struct Column<R, T> {
+    /// The quoted column name.
+    name: &'static str,
+    /// The bind expression, with `{}` standing in for the bind variable.
+    bind_expr: &'static str,
+    /// Bind a value to the named placeholder.
+    bind: fn(&Connection, &Statement, &str, &T) -> Result<Var>,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+impl<R, T: 'static> Column<R, T> {
+    /// Compare the column with the value using the given operator.
+    fn compare(&self, op: &'static str, value: T) -> Condition<R> {
+        let bind = self.bind;
+        Condition {
+            column: self.name,
+            op,
+            bind_expr: self.bind_expr,
+            bind: Some(Box::new(move |conn: &Connection, stmt: &Statement, name: &str| bind(conn, stmt, name, &value))),
+            row: PhantomData,
+        }
+    }
+
+    /// Test the column against `NULL` using the given operator.
+    fn test(&self, op: &'static str) -> Condition<R> {
+        Condition {
+            column: self.name,
+            op,
+            bind_expr: "",
+            bind: None,
+            row: PhantomData,
+        }
+    }
+
+    /// The column equals the value.  Values are taken as the column's type, so a `String` column
+    /// compares with `"x".to_string()` rather than `"x"`.  A nullable column compares with the
+    /// type inside its field's `Option`, without `Some(..)`, and is tested for `NULL` with `is_null`.
+    pub fn eq(&self, value: T) -> Condition<R> {
+        self.compare("=", value)
+    }
+
+    /// The column doesn't equal the value, of the column's type as with `eq`.
+    pub fn ne(&self, value: T) -> Condition<R> {
+        self.compare("<>", value)
+    }
+
+    /// The column is greater than the value, of the column's type as with `eq`.
+    pub fn gt(&self, value: T) -> Condition<R> {
+        self.compare(">", value)
+    }
+
+    /// The column is greater than or equal to the value, of the column's type as with `eq`.
+    pub fn ge(&self, value: T) -> Condition<R> {
+        self.compare(">=", value)
+    }
+
+    /// The column is less than the value, of the column's type as with `eq`.
+    pub fn lt(&self, value: T) -> Condition<R> {
+        self.compare("<", value)
+    }
+
+    /// The column is less than or equal to the value, of the column's type as with `eq`.
+    pub fn le(&self, value: T) -> Condition<R> {
+        self.compare("<=", value)
+    }
+
+    /// The column is `NULL`.
+    pub fn is_null(&self) -> Condition<R> {
+        self.test("IS NULL")
+    }
+
+    /// The column isn't `NULL`.
+    pub fn is_not_null(&self) -> Condition<R> {
+        self.test("IS NOT NULL")
+    }
+
+    /// Order by the column, ascending.
+    pub fn asc(&self) -> SortOrder<R> {
+        SortOrder {
+            column: self.name,
+            direction: "ASC",
+            row: PhantomData,
+        }
+    }
+
+    /// Order by the column, descending.
+    pub fn desc(&self) -> SortOrder<R> {
+        SortOrder {
+            column: self.name,
+            direction: "DESC",
+            row: PhantomData,
+        }
+    }
+}
+
+/// A column of the table of `R` rows, whatever type it holds, i.e. to upsert on.
+pub trait AnyColumn<R> {
+    /// The quoted column name.
+    fn name(&self) -> &'static str;
+}
+
+impl<R, T> AnyColumn<R> for Column<R, T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A `WHERE` clause condition on a column of the table of `R` rows.
+pub struct Condition<R> {
+    /// The quoted column name.
+    column: &'static str,
+    /// The operator.
+    op: &'static str,
+    /// The bind expression, with `{}` standing in for the bind variable.
+    bind_expr: &'static str,
+    /// Bind the compared value to the named placeholder, unless testing against `NULL`.
+    bind: Option<Box<dyn Fn(&Connection, &Statement, &str) -> Result<Var>>>,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+impl<R> Condition<R> {
+    /// The condition SQL, binding the value to the given placeholder.
+    fn sql(&self, placeholder: &str) -> String {
+        match self.bind {
+            Some(_) => format!("{} {} {}", self.column, self.op, self.bind_expr.replace("{}", placeholder)),
+            None => format!("{} {}", self.column, self.op),
+        }
+    }
+}
+
+/// An `ORDER BY` item on a column of the table of `R` rows.
+pub struct SortOrder<R> {
+    /// The quoted column name.
+    column: &'static str,
+    /// `ASC` or `DESC`.
+    direction: &'static str,
+    /// The table.
+    row: PhantomData<R>,
+}
+
+/// A query of `R` rows, rendered to SQL with bind variables for every value.
+pub struct Query<R> {
+    /// The conditions, all of which must hold.
+    conditions: Vec<Condition<R>>,
+    /// The `ORDER BY` items, in order.
+    order: Vec<SortOrder<R>>,
+    /// The maximum number of rows to fetch.
+    limit: Option<u64>,
+}
+
+impl<R: Queryable> Query<R> {
+    /// A query of every row.
+    pub fn new() -> Query<R> {
+        Query {
+            conditions: Vec::new(),
+            order: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Only fetch the rows meeting the condition, as well as any earlier ones.
+    pub fn filter(mut self, condition: Condition<R>) -> Query<R> {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Order the rows by the given column, after any earlier ones.
+    pub fn order_by(mut self, order: SortOrder<R>) -> Query<R> {
+        self.order.push(order);
+        self
+    }
+
+    /// Fetch at most the given number of rows.
+    pub fn limit(mut self, limit: u64) -> Query<R> {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The query SQL.  Compared values are bound to `:q1, :q2, ...` in order, and the limit to
+    /// `:q_limit`.
+    pub fn sql(&self) -> String {
+        let mut sql = R::QUERY_SQL.to_string();
+        for (i, condition) in self.conditions.iter().enumerate() {
+            sql.push_str(if i == 0 { " WHERE " } else { " AND " });
+            sql.push_str(&condition.sql(&format!(":q{}", i + 1)));
+        }
+        for (i, order) in self.order.iter().enumerate() {
+            sql.push_str(if i == 0 { " ORDER BY " } else { ", " });
+            sql.push_str(&format!("{} {}", order.column, order.direction));
+        }
+        if self.limit.is_some() {
+            sql.push_str(" FETCH FIRST :q_limit ROWS ONLY");
+                    }
+        sql
+    }
+
+    /// Fetch the rows from the given connection.
+    pub fn load(&self, conn: &Connection) -> Result<Vec<R>> {
+        let stmt = conn.prepare_stmt(Some(&self.sql()[..]), None, false)?;
+        stmt.set_fetch_array_size(FETCH_ARRAY_SIZE)?;
+        let mut vars = Vec::new();
+        for (i, condition) in self.conditions.iter().enumerate() {
+            if let Some(ref bind) = condition.bind {
+                vars.push(bind(conn, &stmt, &format!(":q{}", i + 1))?);
+            }
+        }
+        if let Some(limit) = self.limit {
+            vars.push(bind_count(conn, &stmt, ":q_limit", limit)?);
+        }
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        R::define(&stmt)?;
+
+        let mut rows = Vec::new();
+        let (mut found, _) = stmt.fetch()?;
+        while found {
+            rows.push(R::from_row(&stmt)?);
+            let (f, _) = stmt.fetch()?;
+            found = f;
+        }
+
+        stmt.close(None)?;
+        Ok(rows)
+    }
+}
+
+/// How array DML handles rows that fail.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchMode {
+    /// Stop at the first failing row, returning an error chained to the `BatchAborted` holding its
+    /// offset and the outcome of the rows before it.
+    Abort,
+    /// Carry on past failing rows, reporting them in `Batch::errors`.
+    Continue,
+}
+
+/// A row that failed in an array DML batch.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    /// The offset of the failing row in the rows given.
+    pub offset: usize,
+    /// The Oracle error message.
+    pub message: String,
+}
+
+/// The outcome of an array DML operation.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Batch {
+    /// The number of rows affected by each row given, in order.
+    pub row_counts: Vec<u64>,
+    /// The rows that failed, with `BatchMode::Continue`.
+    pub errors: Vec<BatchError>,
+}
+
+impl Batch {
+    /// The total number of rows affected.
+    pub fn row_count(&self) -> u64 {
+        self.row_counts.iter().sum()
+    }
+}
+
+/// An array DML operation stopped by a failing row with `BatchMode::Abort`, chained to the error
+/// returned.  The rows before it were applied, and are left for the caller to commit or roll back.
+#[derive(Debug)]
+pub struct BatchAborted {
+    /// The offset of the failing row in the rows given.
+    pub offset: usize,
+    /// The outcome of the rows before the failing row, where Oracle reports it.
+    pub batch: Batch,
+    /// The error executing the failing row.
+    cause: Error,
+}
+
+impl BatchAborted {
+    /// The failure of the row at the given offset of the chunk starting at the given offset of the
+    /// rows given, after the given batch of earlier chunks.  Oracle applied the rows of the chunk
+    /// before it, with the given row counts.
+    pub fn new(batch: Batch, chunk_offset: usize, row_offset: usize, row_counts: Vec<u64>, cause: Error) -> BatchAborted {
+        let mut batch = batch;
+        batch.row_counts.extend(row_counts.into_iter().take(row_offset));
+        BatchAborted {
+            offset: chunk_offset + row_offset,
+            batch,
+            cause,
+        }
+    }
+
+    /// The `BatchAborted` the given error is chained to, if any.
+    pub fn of(e: &Error) -> Option<&BatchAborted> {
+        e.1.next_error.as_ref().and_then(|next| next.downcast_ref())
+    }
+}
+
+impl fmt::Display for BatchAborted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "row {}: {}", self.offset, self.cause)
+    }
+}
+
+impl StdError for BatchAborted {
+    fn description(&self) -> &str {
+        "array DML aborted"
+    }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.cause)
+    }
+}
+
+impl From<BatchAborted> for Error {
+    fn from(aborted: BatchAborted) -> Error {
+        let msg = aborted.to_string();
+        Error::with_chain(aborted, msg)
+    }
+}
+
+/// Execute the bound statement once for each of the given number of rows, which start at the given
+/// offset of the rows given, adding the outcome to the batch.
+fn execute_many(
+    ctxt: &Context,
+    stmt: &Statement,
+    rows: usize,
+    offset: usize,
+    mode: BatchMode,
+    batch: &mut Batch,
+) -> Result<()> {
+    let mut exec_mode = flags::DPI_MODE_EXEC_ARRAY_DML_ROWCOUNTS;
+    if mode == BatchMode::Continue {
+        exec_mode |= flags::DPI_MODE_EXEC_BATCH_ERRORS;
+    }
+    if let Err(e) = stmt.execute_many(exec_mode, rows as u32) {
+        // Without batch errors Oracle stops at the failing row, reporting its offset in the chunk.
+        let row_offset = usize::from(ctxt.get_error().offset());
+        let row_counts = stmt.get_row_counts().unwrap_or_default();
+        let batch = mem::replace(batch, Batch::default());
+        return Err(BatchAborted::new(batch, offset, row_offset, row_counts, e.into()).into());
+    }
+    batch.row_counts.extend(stmt.get_row_counts()?);
+    if mode == BatchMode::Continue {
+        for error in stmt.get_batch_errors()? {
+            batch.errors.push(BatchError {
+                offset: offset + error.offset() as usize,
+                message: error.message(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check a value measured in the given units fits the named column.
+fn check_length(column: &str, length: usize, max_length: usize, unit: &str) -> Result<()> {
+    if length > max_length {
+        let msg = format!("{} value is {} {} long, but the column allows {}", column, length, unit, max_length);
+        Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// The fields modified through the setters since a row was fetched or last updated.  It takes no
+/// part in equality or hashing, so rows compare by value.
+#[derive(Clone, Debug, Default)]
+pub struct Dirty(BTreeSet<&'static str>);
+
+impl Dirty {
+    /// Record the given field as modified.
+    fn mark(&mut self, field: &'static str) {
+        self.0.insert(field);
+    }
+
+    /// Has the given field been modified?
+    fn contains(&self, field: &str) -> bool {
+        self.0.contains(field)
+    }
+
+    /// Have any fields been modified?
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Forget the modifications, i.e. once written.
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl PartialEq for Dirty {
+    fn eq(&self, _other: &Dirty) -> bool {
+        true
+    }
+}
+
+impl Eq for Dirty {}
+
+impl Hash for Dirty {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// The locator values (`ROWID` or primary key) a row was fetched with, so writes go to that row
+/// even if the fields have been modified since.  Like `Dirty`, it takes no part in equality or
+/// hashing.
+#[derive(Clone, Debug)]
+pub struct Located<T>(Option<T>);
+
+impl<T> Default for Located<T> {
+    fn default() -> Located<T> {
+        Located(None)
+    }
+}
+
+impl<T> PartialEq for Located<T> {
+    fn eq(&self, _other: &Located<T>) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Located<T> {}
+
+impl<T> Hash for Located<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Parse a value fetched as text, i.e. a `NUMBER` too wide for a native ODPI type.
+fn parse_text<T>(text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("invalid value '{}': {}", text, e)).into())
+}
+
+/// `Orders` ORM
+#[derive(Getters, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct Orders {
+    /// `order_id` column
+    #[get = "pub"]
+    order_id: i32,
+    /// `code` column
+    #[get = "pub"]
+    code: String,
+    /// `note` column
+    #[get = "pub"]
+    note: Option<String>,
+    /// The fields modified since the row was fetched or last updated.
+    dirty: Dirty,
+    /// The locator values the row was fetched with.
+    located: Located<i32>,
+    }
+
+impl Orders {
+    /// Set the `order_id` field, marking it as modified.
+    pub fn set_order_id(&mut self, order_id: i32) -> &mut Self {
+        self.order_id = order_id;
+        self.dirty.mark("order_id");
+        self
+    }
+
+    /// Mutably borrow the `order_id` field, marking it as modified.
+    pub fn order_id_mut(&mut self) -> &mut i32 {
+        self.dirty.mark("order_id");
+        &mut self.order_id
+    }
+
+    /// Set the `code` field, marking it as modified.
+    pub fn set_code(&mut self, code: String) -> &mut Self {
+        self.code = code;
+        self.dirty.mark("code");
+        self
+    }
+
+    /// Mutably borrow the `code` field, marking it as modified.
+    pub fn code_mut(&mut self) -> &mut String {
+        self.dirty.mark("code");
+        &mut self.code
+    }
+
+    /// Set the `note` field, marking it as modified.
+    pub fn set_note(&mut self, note: Option<String>) -> &mut Self {
+        self.note = note;
+        self.dirty.mark("note");
+        self
+    }
+
+    /// Mutably borrow the `note` field, marking it as modified.
+    pub fn note_mut(&mut self) -> &mut Option<String> {
+        self.dirty.mark("note");
+        &mut self.note
+    }
+
+    /// Have any fields been modified since the row was fetched or last updated?
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Check every length limited field fits its column, counting characters or bytes as the
+    /// column does.
+    pub fn validate(&self) -> Result<()> {
+
+        Ok(())
+    }
+
+    /// The `SELECT` statement used to fetch `Orders` rows.
+    const SELECT: &'static str = "SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\"";
+
+    /// Read a `Orders` from the current row of the given statement.
+    fn from_row(stmt: &Statement) -> Result<Orders> {
+
+        let mut row = Orders {
+
+            order_id: fetch(stmt, 1, |data| Ok(data.get_int64() as i32))?,
+
+            code: fetch(stmt, 2, |data| Ok(data.get_string()))?,
+
+            note: fetch_opt(stmt, 3, |data| Ok(data.get_string()))?,
+            dirty: Default::default(),
+            located: Default::default(),
+
+        };
+        row.located = Located(Some(row.order_id.clone()));
+        Ok(row)
+    }
+
+    /// Define the fetched columns that aren't fetched as their default types.
+    fn define(stmt: &Statement) -> Result<()> {
+
+        stmt.define_value(1, ODPIOracleTypeNum::Number, ODPINativeTypeNum::Int64, 0, false, None)?;
+
+        Ok(())
+    }
+
+    /// Execute the given query of `Orders` rows, with the variables `bind` binds, ready to
+    /// fetch rows `FETCH_ARRAY_SIZE` at a time.
+    fn select<F>(conn: &Connection, sql: &str, bind: F) -> Result<Statement>
+    where
+        F: FnOnce(&Statement) -> Result<Vec<Var>>,
+    {
+        let stmt = conn.prepare_stmt(Some(sql), None, false)?;
+        stmt.set_fetch_array_size(FETCH_ARRAY_SIZE)?;
+        let _vars = bind(&stmt)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        Self::define(&stmt)?;
+        Ok(stmt)
+    }
+
+    /// Fetch the remaining rows of the executed statement, then close it.
+    fn fetch_rows(stmt: Statement) -> Result<Vec<Orders>> {
+        let mut rows = Vec::new();
+        let (mut found, _) = stmt.fetch()?;
+
+        while found {
+            rows.push(Self::from_row(&stmt)?);
+            let (f, _) = stmt.fetch()?;
+            found = f;
+        }
+
+        stmt.close(None)?;
+        Ok(rows)
+    }
+
+    /// Fetch a vector of `Orders` from the given connection.  By default, all rows will be
+    /// fetched.
+    pub fn fetch(conn: &Connection) -> Result<Vec<Orders>> {
+        Self::fetch_rows(Self::select(conn, Self::SELECT, |_| Ok(Vec::new()))?)
+    }
+
+    /// Lazily fetch `Orders` rows from the given connection, `FETCH_ARRAY_SIZE` rows per
+    /// round trip, so only those are held in memory.  Iteration ends after the first error.
+    pub fn fetch_iter(conn: &Connection) -> impl Iterator<Item = Result<Orders>> {
+        Rows::new(Self::select(conn, Self::SELECT, |_| Ok(Vec::new())), Self::from_row)
+    }
+
+    /// Build a query of `Orders` rows, filtered and ordered by the typed columns of
+    /// `orders`, i.e. `Orders::query().filter(orders::X.gt(1)).load(conn)`.
+    pub fn query() -> Query<Orders> {
+        Query::new()
+    }
+
+    /// Fetch the page of at most `limit` `Orders` rows that skips the first `offset` rows,
+    /// ordered by `"ORDER_ID"`.  Every row before the offset is still read by the database, so
+    /// prefer `fetch_after` for deep pages.
+    pub fn fetch_page(conn: &Connection, offset: u64, limit: u64) -> Result<Vec<Orders>> {
+        let stmt = Self::select(conn, "SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\" ORDER BY \"ORDER_ID\" OFFSET :page_offset ROWS FETCH NEXT :page_limit ROWS ONLY", |stmt| {
+            Ok(vec![
+                bind_count(conn, stmt, ":page_offset", offset)?,
+                bind_count(conn, stmt, ":page_limit", limit)?,
+                            ])
+        })?;
+        Self::fetch_rows(stmt)
+    }
+
+    /// Fetch at most `limit` `Orders` rows ordered by `"ORDER_ID"`, starting after the
+    /// row with the given key, or from the first row if `None`.  Pass the key of the last row
+    /// fetched to get the next page; unlike `fetch_page`, the cost doesn't grow with the depth.
+    pub fn fetch_after(
+        conn: &Connection,
+        last_key: Option<&i32>,
+        limit: u64,
+    ) -> Result<Vec<Orders>> {
+        let stmt = match last_key {
+            None => Self::select(conn, "SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\" ORDER BY \"ORDER_ID\" FETCH FIRST :page_limit ROWS ONLY", |stmt| {
+                Ok(vec![bind_count(conn, stmt, ":page_limit", limit)?])
+            })?,
+            Some(key) => Self::select(conn, "SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\" WHERE (\"ORDER_ID\" > :p_order_id) ORDER BY \"ORDER_ID\" FETCH FIRST :page_limit ROWS ONLY", |stmt| {
+                Ok(vec![
+                    bind(
+                        conn,
+                        stmt,
+                        ":p_order_id",
+                        ODPIOracleTypeNum::Number,
+                        ODPINativeTypeNum::Int64,
+                        0,
+                        &[key],
+                        |v| Some(*v),
+                        |data, v| {
+                            data.set_int64(i64::from(*v));
+                            Ok(())
+                        },
+                    )?,
+                    bind_count(conn, stmt, ":page_limit", limit)?,
+                ])
+            })?,
+        };
+        Self::fetch_rows(stmt)
+    }
+
+
+
+    /// Insert this `Orders` as a new row.  `None` fields are inserted as `NULL`, and identity
+    /// columns are left to the database.  Returns the number of rows inserted.
+    pub fn insert(&self, conn: &Connection) -> Result<u64> {
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some("INSERT INTO \"ORDERS\" (\"ORDER_ID\", \"CODE\", \"NOTE\") VALUES (:p_order_id, :p_code, :p_note)"), None, false)?;
+        let _p_order_id = Self::bind_order_id(conn, &stmt, &[self])?;
+        let _p_code = Self::bind_code(conn, &stmt, &[self])?;
+        let _p_note = Self::bind_note(conn, &stmt, &[self])?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+
+    /// Insert this `Orders` as a new row, then refresh it from the inserted row, filling in
+    /// database generated values such as identities, defaults and trigger set columns.  Returns the
+    /// number of rows inserted.
+    pub fn insert_returning(&mut self, conn: &Connection) -> Result<u64> {
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some("INSERT INTO \"ORDERS\" (\"ORDER_ID\", \"CODE\", \"NOTE\") VALUES (:p_order_id, :p_code, :p_note) RETURNING ROWID INTO :rid"), None, false)?;
+        let _p_order_id = Self::bind_order_id(conn, &stmt, &[self])?;
+        let _p_code = Self::bind_code(conn, &stmt, &[self])?;
+        let _p_note = Self::bind_note(conn, &stmt, &[self])?;
+        let rowid = conn.new_var(ODPIOracleTypeNum::Rowid, ODPINativeTypeNum::Rowid, 1, 0, false, false)?;
+        stmt.bind_by_name(":rid", &rowid)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+
+        let stmt = conn.prepare_stmt(Some("SELECT \"ORDER_ID\", \"CODE\", \"NOTE\" FROM \"ORDERS\" WHERE ROWID = :rid"), None, false)?;
+        stmt.bind_by_name(":rid", &rowid)?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        Self::define(&stmt)?;
+        let (found, _) = stmt.fetch()?;
+        if found {
+            *self = Self::from_row(&stmt)?;
+        }
+        stmt.close(None)?;
+        Ok(count)
+    }
+
+    /// Insert the given rows with array DML, `BATCH_SIZE` rows per round trip.  Returns the rows
+    /// inserted by each row and, with `BatchMode::Continue`, the offsets of the rows that failed.
+    /// The context the connection was made with reports the failing row with `BatchMode::Abort`.
+    pub fn insert_many(conn: &Connection, ctxt: &Context, rows: &[Orders], mode: BatchMode) -> Result<Batch> {
+        for row in rows {
+            row.validate()?;
+        }
+        let stmt = conn.prepare_stmt(Some("INSERT INTO \"ORDERS\" (\"ORDER_ID\", \"CODE\", \"NOTE\") VALUES (:p_order_id, :p_code, :p_note)"), None, false)?;
+        let mut batch = Batch::default();
+        for (n, chunk) in rows.chunks(BATCH_SIZE).enumerate() {
+            let chunk: Vec<&Self> = chunk.iter().collect();
+            let _p_order_id = Self::bind_order_id(conn, &stmt, &chunk)?;
+            let _p_code = Self::bind_code(conn, &stmt, &chunk)?;
+            let _p_note = Self::bind_note(conn, &stmt, &chunk)?;
+            execute_many(ctxt, &stmt, chunk.len(), n * BATCH_SIZE, mode, &mut batch)?;
+        }
+        stmt.close(None)?;
+        Ok(batch)
+    }
+
+
+    /// Update the row this `Orders` was fetched from, only writing the columns modified
+    /// through the setters since.  Concurrent changes to other columns are left alone.  Returns the
+    /// number of rows updated, which is zero if no updatable column was modified.  Either way, the
+    /// modifications are forgotten.
+    pub fn update(&mut self, conn: &Connection) -> Result<u64> {
+        let mut set = Vec::new();
+        if self.dirty.contains("code") {
+            set.push("\"CODE\" = :p_code");
+        }
+        if self.dirty.contains("note") {
+            set.push("\"NOTE\" = :p_note");
+        }
+        if set.is_empty() {
+            self.dirty.clear();
+            return Ok(0);
+        }
+
+        self.validate()?;
+        let sql = ["UPDATE \"ORDERS\" SET ", &set.join(", "), " WHERE \"ORDER_ID\" = :p_order_id"].concat();
+        let stmt = conn.prepare_stmt(Some(&sql[..]), None, false)?;
+        let mut vars = Vec::new();
+        if self.dirty.contains("code") {
+            vars.push(Self::bind_code(conn, &stmt, &[self])?);
+        }
+        if self.dirty.contains("note") {
+            vars.push(Self::bind_note(conn, &stmt, &[self])?);
+        }
+        vars.extend(Self::locate(conn, &stmt, &[self])?);
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        self.dirty.clear();
+        Ok(count)
+    }
+
+    /// Update the rows the given `Orders`s were fetched from with array DML, `BATCH_SIZE`
+    /// rows per round trip, writing every writable column.  Returns the rows updated by each row
+    /// and, with `BatchMode::Continue`, the offsets of the rows that failed.  The context the
+    /// connection was made with reports the failing row with `BatchMode::Abort`.
+    pub fn update_many(conn: &Connection, ctxt: &Context, rows: &[Orders], mode: BatchMode) -> Result<Batch> {
+        for row in rows {
+            row.validate()?;
+        }
+        let stmt = conn.prepare_stmt(Some("UPDATE \"ORDERS\" SET \"CODE\" = :p_code, \"NOTE\" = :p_note WHERE \"ORDER_ID\" = :p_order_id"), None, false)?;
+        let mut batch = Batch::default();
+        for (n, chunk) in rows.chunks(BATCH_SIZE).enumerate() {
+            let chunk: Vec<&Self> = chunk.iter().collect();
+            let _p_code = Self::bind_code(conn, &stmt, &chunk)?;
+            let _p_note = Self::bind_note(conn, &stmt, &chunk)?;
+            let _locator = Self::locate(conn, &stmt, &chunk)?;
+            execute_many(ctxt, &stmt, chunk.len(), n * BATCH_SIZE, mode, &mut batch)?;
+        }
+        stmt.close(None)?;
+        Ok(batch)
+    }
+
+
+    /// Delete the row this `Orders` was fetched from.  Returns the number of rows deleted.
+    pub fn delete(&self, conn: &Connection) -> Result<u64> {
+        let stmt = conn.prepare_stmt(Some("DELETE FROM \"ORDERS\" WHERE \"ORDER_ID\" = :p_order_id"), None, false)?;
+        let _locator = Self::locate(conn, &stmt, &[self])?;
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+
+
+    /// The quoted table name.
+    const TABLE: &'static str = "\"ORDERS\"";
+
+    /// The `(field, quoted column, bind expression, inserted)` of each writable field.
+    const COLUMNS: &'static [(&'static str, &'static str, &'static str, bool)] = &[
+        ("order_id", "\"ORDER_ID\"", ":p_order_id", true),
+        ("code", "\"CODE\"", ":p_code", true),
+        ("note", "\"NOTE\"", ":p_note", true),
+    ];
+
+    /// The fields of each key rows can be upserted on, primary key first.
+    pub const KEYS: &'static [&'static [&'static str]] = &[
+        &["order_id"],
+        &["code"],
+    ];
+
+    /// The fields `upsert` updates when a row with the same first key exists.
+    pub const UPSERT_UPDATE: &'static [&'static str] = &["code", "note"];
+
+    /// Insert this `Orders`, or update the existing row with the same first of `KEYS`.
+    /// Returns the number of rows merged.
+    pub fn upsert(&self, conn: &Connection) -> Result<u64> {
+        self.merge(conn, Self::KEYS[0], Self::UPSERT_UPDATE)
+    }
+
+    /// Insert this `Orders`, or update the `update` columns of the existing row matching it
+    /// on the `key` columns, which must be one of `KEYS`.  Both are typed columns from
+    /// `orders`.  With no `update` columns, existing rows are left alone.  Returns the number
+    /// of rows merged.
+    pub fn upsert_on(
+        &self,
+        conn: &Connection,
+        key: &[&dyn AnyColumn<Self>],
+        update: &[&dyn AnyColumn<Self>],
+    ) -> Result<u64> {
+        let fields = |columns: &[&dyn AnyColumn<Self>]| -> Result<Vec<&'static str>> {
+            let mut fields = Vec::new();
+            for column in columns {
+                match Self::COLUMNS.iter().find(|c| c.1 == column.name()) {
+                    Some(c) => fields.push(c.0),
+                    None => {
+                        let msg = format!("Orders column {} isn't writable", column.name());
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+                    }
+                }
+            }
+            Ok(fields)
+        };
+        self.merge(conn, &fields(key)?, &fields(update)?)
+    }
+
+    /// Insert this `Orders`, or update the `update` fields of the existing row matching it on
+    /// the `key` fields, which must be one of `KEYS`.  Returns the number of rows merged.
+    fn merge(&self, conn: &Connection, key: &[&str], update: &[&str]) -> Result<u64> {
+        if !Self::KEYS.iter().any(|k| k.len() == key.len() && k.iter().all(|f| key.contains(f))) {
+            let msg = format!("{:?} is not a key of Orders", key);
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+        }
+        let column = |field: &str| {
+            Self::COLUMNS.iter().find(|c| c.0 == field).ok_or_else(|| {
+                let msg = format!("Orders has no writable field '{}'", field);
+                io::Error::new(io::ErrorKind::InvalidInput, msg)
+            })
+        };
+
+        let mut on = Vec::new();
+        for &field in key {
+            let c = column(field)?;
+            on.push(format!("t.{} = {}", c.1, c.2));
+        }
+        let mut set = Vec::new();
+        for &field in update {
+            if key.contains(&field) {
+                let msg = format!("Orders key field '{}' can't be updated on match", field);
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into());
+            }
+            let c = column(field)?;
+            set.push(format!("t.{} = {}", c.1, c.2));
+        }
+        let inserted: Vec<_> = Self::COLUMNS.iter().filter(|c| c.3).collect();
+
+        let mut sql = format!("MERGE INTO {} t USING dual ON ({})", Self::TABLE, on.join(" AND "));
+        if !set.is_empty() {
+            sql.push_str(&format!(" WHEN MATCHED THEN UPDATE SET {}", set.join(", ")));
+        }
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+            inserted.iter().map(|c| c.1).collect::<Vec<_>>().join(", "),
+            inserted.iter().map(|c| c.2).collect::<Vec<_>>().join(", ")
+        ));
+
+        self.validate()?;
+        let stmt = conn.prepare_stmt(Some(&sql[..]), None, false)?;
+        let mut bound = BTreeSet::new();
+        let mut vars = Vec::new();
+        for field in key.iter().chain(update).chain(inserted.iter().map(|c| &c.0)) {
+            if bound.insert(*field) {
+                vars.push(Self::field_var(conn, &stmt, field, &[self])?);
+            }
+        }
+        let _ = stmt.execute(flags::DPI_MODE_EXEC_DEFAULT)?;
+        let count = stmt.get_row_count()?;
+        stmt.close(None)?;
+        Ok(count)
+    }
+
+    /// Bind the named field of each row to its placeholder of the given statement.
+    fn field_var(conn: &Connection, stmt: &Statement, field: &str, rows: &[&Self]) -> Result<Var> {
+        match field {
+            "order_id" => Self::bind_order_id(conn, stmt, rows),
+            "code" => Self::bind_code(conn, stmt, rows),
+            "note" => Self::bind_note(conn, stmt, rows),
+            _ => {
+                let msg = format!("Orders has no writable field '{}'", field);
+                Err(io::Error::new(io::ErrorKind::InvalidInput, msg).into())
+            }
+        }
+    }
+
+
+    /// Bind the locator each row was fetched with to the locating placeholders of the given
+    /// statement.  Rows that weren't fetched are located by their current fields.
+    fn locate(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Vec<Var>> {
+        Ok(vec![
+            bind(
+                conn,
+                stmt,
+                ":p_order_id",
+                ODPIOracleTypeNum::Number,
+                ODPINativeTypeNum::Int64,
+                0,
+                rows,
+                |row| match row.located.0 {
+                    Some(ref located) => Some(located),
+                    None => Some(&row.order_id),
+                },
+                |data, v| {
+                    data.set_int64(i64::from(*v));
+                    Ok(())
+                },
+            )?,
+        ])
+    }
+
+
+    /// Bind the `order_id` field of each row to `:p_order_id` of the given statement.
+    fn bind_order_id(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            ":p_order_id",
+            ODPIOracleTypeNum::Number,
+            ODPINativeTypeNum::Int64,
+            0,
+            rows,
+            |row| Some(&row.order_id),
+            |data, v| {
+                data.set_int64(i64::from(*v));
+                Ok(())
+            },
+        )
+    }
+
+
+    /// Bind the `code` field of each row to `:p_code` of the given statement.
+    fn bind_code(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            ":p_code",
+            ODPIOracleTypeNum::Varchar,
+            ODPINativeTypeNum::Bytes,
+            0,
+            rows,
+            |row| Some(&row.code),
+            |data, v| {
+                data.set_bytes(v.as_bytes());
+                Ok(())
+            },
+        )
+    }
+
+
+    /// Bind the `note` field of each row to `:p_note` of the given statement.
+    fn bind_note(conn: &Connection, stmt: &Statement, rows: &[&Self]) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            ":p_note",
+            ODPIOracleTypeNum::Varchar,
+            ODPINativeTypeNum::Bytes,
+            0,
+            rows,
+            |row| row.note.as_ref(),
+            |data, v| {
+                data.set_bytes(v.as_bytes());
+                Ok(())
+            },
+        )
+    }
+
+}
+
+impl Queryable for Orders {
+    const QUERY_SQL: &'static str = "SELECT \"ORDER_ID\" c1, \"CODE\" c2, \"NOTE\" c3 FROM \"ORDERS\"";
+    const QUERY_COLUMNS: &'static str = "c1, c2, c3";
+
+    fn define(stmt: &Statement) -> Result<()> {
+        Orders::define(stmt)
+    }
+
+    fn from_row(stmt: &Statement) -> Result<Orders> {
+        Orders::from_row(stmt)
+    }
+}
+
+
+/// Typed columns of `Orders`, for building queries and picking the keys to upsert on.
+pub mod orders {
+    use super::*;
+
+    /// The `ORDER_ID` column.
+    pub const ORDER_ID: Column<Orders, i32> = Column {
+        name: "\"ORDER_ID\"",
+        bind_expr: "{}",
+        bind: bind_order_id,
+        row: PhantomData,
+    };
+
+    /// Bind a `ORDER_ID` value to the named placeholder.
+    fn bind_order_id(conn: &Connection, stmt: &Statement, name: &str, value: &i32) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            name,
+            ODPIOracleTypeNum::Number,
+            ODPINativeTypeNum::Int64,
+            0,
+            &[value],
+            |v| Some(*v),
+            |data, v| {
+                data.set_int64(i64::from(*v));
+                Ok(())
+            },
+        )
+    }
+
+    /// The `CODE` column.
+    pub const CODE: Column<Orders, String> = Column {
+        name: "\"CODE\"",
+        bind_expr: "{}",
+        bind: bind_code,
+        row: PhantomData,
+    };
+
+    /// Bind a `CODE` value to the named placeholder.
+    fn bind_code(conn: &Connection, stmt: &Statement, name: &str, value: &String) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            name,
+            ODPIOracleTypeNum::Varchar,
+            ODPINativeTypeNum::Bytes,
+            0,
+            &[value],
+            |v| Some(*v),
+            |data, v| {
+                data.set_bytes(v.as_bytes());
+                Ok(())
+            },
+        )
+    }
+
+    /// The `NOTE` column.
+    pub const NOTE: Column<Orders, String> = Column {
+        name: "\"NOTE\"",
+        bind_expr: "{}",
+        bind: bind_note,
+        row: PhantomData,
+    };
+
+    /// Bind a `NOTE` value to the named placeholder.
+    fn bind_note(conn: &Connection, stmt: &Statement, name: &str, value: &String) -> Result<Var> {
+        bind(
+            conn,
+            stmt,
+            name,
+            ODPIOracleTypeNum::Varchar,
+            ODPINativeTypeNum::Bytes,
+            0,
+            &[value],
+            |v| Some(*v),
+            |data, v| {
+                data.set_bytes(v.as_bytes());
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,70 @@
+//! Compile the code generated for `ORDERS`, keyed by `ORDER_ID` and unique on `CODE`, against an
+//! `error_chain` error module like the ones the generated code is meant to live beside.
+//!
+//! The typed queries are built without a connection, so their SQL is checked here too.
+//!
+//! `tests/fixtures/orm.rs` is checked against the output of the template by the `generated_fixture`
+//! test in `tmpl`.
+#[macro_use]
+extern crate error_chain;
+#[macro_use]
+extern crate getset;
+extern crate mimir;
+
+mod error {
+    error_chain! {
+        foreign_links {
+            Io(::std::io::Error);
+            Mimir(::mimir::Error);
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[path = "fixtures/orm.rs"]
+mod orm;
+
+use error::Error;
+use orm::{orders, Batch, BatchAborted, Orders};
+
+#[test]
+fn batch_aborted_partway() {
+    let batch = Batch {
+        row_counts: vec![1; 1000],
+        errors: Vec::new(),
+    };
+    let cause: Error = "ORA-00001: unique constraint (SCOTT.ORDERS_CODE) violated".into();
+    let e: Error = BatchAborted::new(batch, 1000, 7, vec![1; 10], cause).into();
+    let aborted = BatchAborted::of(&e).expect("chained to the aborted batch");
+    assert_eq!(aborted.offset, 1007);
+    assert_eq!(aborted.batch.row_counts.len(), 1007);
+    assert!(aborted.batch.errors.is_empty());
+    assert!(e.to_string().starts_with("row 1007: ORA-00001"));
+    let chained = e.iter().nth(1).expect("chained error");
+    assert!(chained.to_string().starts_with("row 1007"));
+}
+
+#[test]
+fn batch_aborted_only_when_chained() {
+    let e: Error = "ORA-03113: end-of-file on communication channel".into();
+    assert!(BatchAborted::of(&e).is_none());
+}
+
+#[test]
+fn typed_queries() {
+    // Values are the column's type, and nullable columns compare with the type inside the `Option`.
+    let query = Orders::query()
+        .filter(orders::ORDER_ID.gt(1))
+        .filter(orders::CODE.eq("A-1".to_string()))
+        .filter(orders::NOTE.ne("rush".to_string()))
+        .filter(orders::NOTE.is_not_null())
+        .order_by(orders::CODE.desc())
+        .limit(10);
+    assert_eq!(
+        query.sql(),
+        "SELECT \"ORDER_ID\" c1, \"CODE\" c2, \"NOTE\" c3 FROM \"ORDERS\" \
+         WHERE \"ORDER_ID\" > :q1 AND \"CODE\" = :q2 AND \"NOTE\" <> :q3 AND \"NOTE\" IS NOT NULL \
+         ORDER BY \"CODE\" DESC FETCH FIRST :q_limit ROWS ONLY"
+    );
+}